@@ -0,0 +1,456 @@
+use crate::config::PuzzleFiles;
+use crate::error::Error;
+use crate::puzzle::Answer;
+use crate::registry;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(usize),
+    Total,
+}
+
+impl Stage {
+    pub fn key(self) -> String {
+        match self {
+            Self::Parse => String::from("parse"),
+            Self::Part(part) => format!("part_{part}"),
+            Self::Total => String::from("total"),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Self::Parse),
+            "total" => Some(Self::Total),
+            _ => key.strip_prefix("part_")?.parse().ok().map(Self::Part),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "Parse"),
+            Self::Part(part) => write!(f, "Part {part}"),
+            Self::Total => write!(f, "Total"),
+        }
+    }
+}
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+
+        let percentile = |percent: usize| {
+            let rank = (percent * samples.len()).div_ceil(100).max(1);
+            samples[rank - 1]
+        };
+
+        let stats = Self {
+            min: *samples.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        };
+
+        Some(stats)
+    }
+}
+
+pub struct Measurement {
+    pub stage: Stage,
+    pub stats: Stats,
+    pub baseline: Option<Duration>,
+}
+
+impl Measurement {
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline.filter(|baseline| !baseline.is_zero())?;
+
+        Some(self.stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change()
+            .is_some_and(|change| change * 100.0 > threshold)
+    }
+}
+
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u16,
+    pub runs: usize,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Benchmark {
+    pub fn run(year: u16, day: u16, runs: usize, baseline: &Baseline) -> anyhow::Result<Self> {
+        let Some(solver) = registry::solver(year, day) else {
+            return Err(Error::NoSolver { year, day }.into());
+        };
+
+        let input = PuzzleFiles::new(year, day).read_input()?;
+
+        solver.solve(&input)?;
+
+        let answers = (0..runs)
+            .map(|_| solver.solve(&input))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let stages = [Stage::Parse]
+            .into_iter()
+            .chain((1..=answers[0].results.len()).map(Stage::Part))
+            .chain([Stage::Total]);
+
+        let measurements = stages
+            .filter_map(|stage| {
+                let samples = answers
+                    .iter()
+                    .filter_map(|answer| Self::sample(answer, stage))
+                    .collect_vec();
+
+                let measurement = Measurement {
+                    stage,
+                    stats: Stats::from_samples(samples)?,
+                    baseline: baseline.get(year, day, stage),
+                };
+
+                Some(measurement)
+            })
+            .collect();
+
+        Ok(Self {
+            year,
+            day,
+            runs,
+            measurements,
+        })
+    }
+
+    fn sample(answer: &Answer, stage: Stage) -> Option<Duration> {
+        match stage {
+            Stage::Parse => answer.timings.parse,
+            Stage::Part(part) => answer.timings.parts.get(part - 1).copied().flatten(),
+            Stage::Total => Some(answer.timings.total),
+        }
+    }
+}
+
+pub struct Failure {
+    pub year: u16,
+    pub day: u16,
+    pub error: anyhow::Error,
+}
+
+pub struct BenchReport {
+    pub benchmarks: Vec<Benchmark>,
+    pub failures: Vec<Failure>,
+    pub threshold: f64,
+}
+
+impl BenchReport {
+    pub fn regressions(&self) -> usize {
+        self.benchmarks
+            .iter()
+            .flat_map(|benchmark| &benchmark.measurements)
+            .filter(|measurement| measurement.is_regression(self.threshold))
+            .count()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.regressions() == 0 && self.failures.is_empty()
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for benchmark in &self.benchmarks {
+            writeln!(
+                f,
+                "=== BENCH {year}/{day:0>2} ({runs} runs) ===",
+                year = benchmark.year,
+                day = benchmark.day,
+                runs = benchmark.runs
+            )?;
+
+            for measurement in &benchmark.measurements {
+                let stats = &measurement.stats;
+
+                write!(
+                    f,
+                    "{stage}: min {min:.2?}, median {median:.2?}, p95 {p95:.2?}",
+                    stage = measurement.stage,
+                    min = stats.min,
+                    median = stats.median,
+                    p95 = stats.p95
+                )?;
+
+                if let (Some(baseline), Some(change)) = (measurement.baseline, measurement.change())
+                {
+                    let label = if measurement.is_regression(self.threshold) {
+                        "REGRESSION "
+                    } else {
+                        ""
+                    };
+
+                    write!(f, " [{label}{:+.1}% vs {baseline:.2?}]", change * 100.0)?;
+                }
+
+                writeln!(f)?;
+            }
+        }
+
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "=== BENCH {year}/{day:0>2}: ERROR ({error:#}) ===",
+                year = failure.year,
+                day = failure.day,
+                error = failure.error
+            )?;
+        }
+
+        writeln!(
+            f,
+            "=== {regressions} regressions, {errors} errors ===",
+            regressions = self.regressions(),
+            errors = self.failures.len()
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u16, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        let mut baseline = Self::default();
+
+        for (idx, line) in content.lines().enumerate() {
+            let Some((year, day, stage, nanos)) = line.split('\t').collect_tuple() else {
+                anyhow::bail!("invalid baseline entry at line {}: '{line}'", idx + 1);
+            };
+
+            let stage = Stage::from_key(stage)
+                .ok_or_else(|| anyhow::anyhow!("unknown stage at line {}: '{stage}'", idx + 1))?;
+
+            let median = Duration::from_nanos(nanos.parse()?);
+
+            baseline
+                .medians
+                .insert((year.parse()?, day.parse()?, stage), median);
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn get(&self, year: u16, day: u16, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(year, day, stage)).copied()
+    }
+
+    pub fn record(&mut self, report: &BenchReport) {
+        for benchmark in &report.benchmarks {
+            for measurement in &benchmark.measurements {
+                let key = (benchmark.year, benchmark.day, measurement.stage);
+                self.medians.insert(key, measurement.stats.median);
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let content = self
+            .medians
+            .iter()
+            .map(|((year, day, stage), median)| {
+                format!("{year}\t{day}\t{}\t{}\n", stage.key(), median.as_nanos())
+            })
+            .join("");
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, content)?;
+
+        Ok(())
+    }
+}
+
+pub fn default_baseline_path() -> PathBuf {
+    PuzzleFiles::data_dir().join("bench_baseline.tsv")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::{env, process};
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    fn measurement(median: u64, baseline: Option<u64>) -> Measurement {
+        let median = Duration::from_millis(median);
+
+        Measurement {
+            stage: Stage::Total,
+            stats: Stats {
+                min: median,
+                median,
+                p95: median,
+            },
+            baseline: baseline.map(Duration::from_millis),
+        }
+    }
+
+    #[rstest]
+    #[case(&[5], 5, 5, 5)]
+    #[case(&[4, 1, 3, 2], 1, 2, 4)]
+    #[case(&[3, 1, 2], 1, 2, 3)]
+    #[case(&(1..=20).rev().collect_vec(), 1, 10, 19)]
+    #[case(&(1..=100).collect_vec(), 1, 50, 95)]
+    fn stats_tests(
+        #[case] samples: &[u64],
+        #[case] expected_min: u64,
+        #[case] expected_median: u64,
+        #[case] expected_p95: u64,
+    ) {
+        let stats = Stats::from_samples(millis(samples)).unwrap();
+
+        assert_eq!(
+            [stats.min, stats.median, stats.p95],
+            millis(&[expected_min, expected_median, expected_p95])[..]
+        );
+    }
+
+    #[test]
+    fn stats_without_samples() {
+        assert!(Stats::from_samples(Vec::new()).is_none());
+    }
+
+    #[rstest]
+    #[case(110, Some(100), 5.0, true)]
+    #[case(110, Some(100), 10.0, false)]
+    #[case(111, Some(100), 10.0, true)]
+    #[case(90, Some(100), 0.0, false)]
+    #[case(110, Some(0), 5.0, false)]
+    #[case(110, None, 5.0, false)]
+    fn regression_tests(
+        #[case] median: u64,
+        #[case] baseline: Option<u64>,
+        #[case] threshold: f64,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            measurement(median, baseline).is_regression(threshold),
+            expected
+        );
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = env::temp_dir().join(format!("aoc_bench_baseline_{}.tsv", process::id()));
+
+        let report = BenchReport {
+            benchmarks: vec![Benchmark {
+                year: 2015,
+                day: 4,
+                runs: 3,
+                measurements: vec![
+                    Measurement {
+                        stage: Stage::Part(2),
+                        ..measurement(250, None)
+                    },
+                    measurement(300, Some(1)),
+                ],
+            }],
+            failures: Vec::new(),
+            threshold: 10.0,
+        };
+
+        let mut baseline = Baseline::default();
+        baseline.record(&report);
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path);
+        fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_eq!(
+            loaded.get(2015, 4, Stage::Part(2)),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            loaded.get(2015, 4, Stage::Total),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(loaded.get(2015, 4, Stage::Parse), None);
+        assert_eq!(loaded.medians.len(), 2);
+    }
+
+    #[test]
+    fn baseline_save_creates_directory() {
+        let dir = env::temp_dir().join(format!("aoc_bench_dir_{}", process::id()));
+        let path = dir.join("test_data").join("bench_baseline.tsv");
+
+        let mut baseline = Baseline::default();
+        baseline
+            .medians
+            .insert((2015, 1, Stage::Total), Duration::from_millis(5));
+
+        let saved = baseline.save(&path);
+        let loaded = Baseline::load(&path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        saved.unwrap();
+        assert_eq!(
+            loaded.unwrap().get(2015, 1, Stage::Total),
+            Some(Duration::from_millis(5))
+        );
+    }
+
+    #[test]
+    fn baseline_missing_file_is_empty() {
+        let path = env::temp_dir().join(format!("aoc_bench_missing_{}.tsv", process::id()));
+
+        assert!(Baseline::load(&path).unwrap().medians.is_empty());
+    }
+
+    #[rstest]
+    #[case("2015\t4\ttotal")]
+    #[case("2015\t4\tpart_x\t100")]
+    #[case("2015\tfour\ttotal\t100")]
+    fn baseline_negative_tests(#[case] content: &str) {
+        let path = env::temp_dir().join(format!(
+            "aoc_bench_invalid_{}_{}.tsv",
+            process::id(),
+            content.len()
+        ));
+
+        fs::write(&path, content).unwrap();
+        let loaded = Baseline::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(loaded.is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::puzzle::Metadata;
+use crate::registry;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+const DAYS_PER_YEAR: u16 = 25;
+const PROGRESS_HEADING: &str = "## Progress";
+
+pub struct Catalog {
+    entries: Vec<&'static Metadata>,
+}
+
+impl Catalog {
+    pub fn new(years: &[u16]) -> Self {
+        let entries = years
+            .iter()
+            .flat_map(|&year| registry::days(year).iter().map(move |&day| (year, day)))
+            .filter_map(|(year, day)| registry::metadata(year, day))
+            .collect();
+
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[&'static Metadata] {
+        &self.entries
+    }
+
+    pub fn progress_line(&self, year: u16) -> String {
+        let completed = self
+            .entries
+            .iter()
+            .filter(|metadata| metadata.year == year && metadata.is_complete())
+            .map(|metadata| metadata.day)
+            .collect_vec();
+
+        let bar: String = (1..=DAYS_PER_YEAR)
+            .map(|day| {
+                if completed.contains(&day) {
+                    '■'
+                } else {
+                    '□'
+                }
+            })
+            .collect();
+
+        format!(
+            "{year}: {bar} {count}/{DAYS_PER_YEAR}",
+            count = completed.len()
+        )
+    }
+
+    pub fn update_readme(&self, path: &Path) -> anyhow::Result<()> {
+        let readme = fs::read_to_string(path)?;
+        let lines = readme.lines().collect_vec();
+
+        let Some(heading) = lines.iter().position(|line| *line == PROGRESS_HEADING) else {
+            anyhow::bail!("no '{PROGRESS_HEADING}' section in {}", path.display());
+        };
+
+        let end = lines[heading + 1..]
+            .iter()
+            .position(|line| line.starts_with('#'))
+            .map_or(lines.len(), |offset| heading + 1 + offset);
+
+        let mut years = lines[heading + 1..end]
+            .iter()
+            .filter_map(|line| line.split_once(':'))
+            .filter_map(|(year, _)| year.parse::<u16>().ok())
+            .chain(self.entries.iter().map(|metadata| metadata.year))
+            .collect_vec();
+
+        years.sort_unstable();
+        years.dedup();
+
+        let progress = years.into_iter().map(|year| self.progress_line(year));
+        let mut section = vec![String::new()];
+        section.extend(progress);
+
+        if end < lines.len() {
+            section.push(String::new());
+        }
+
+        let updated = lines[..=heading]
+            .iter()
+            .map(|line| line.to_string())
+            .chain(section)
+            .chain(lines[end..].iter().map(|line| line.to_string()))
+            .join("\n");
+
+        let updated = if readme.ends_with('\n') {
+            updated + "\n"
+        } else {
+            updated
+        };
+
+        fs::write(path, updated)?;
+
+        Ok(())
+    }
+
+    fn years(&self) -> Vec<u16> {
+        self.entries
+            .iter()
+            .map(|metadata| metadata.year)
+            .dedup()
+            .collect()
+    }
+}
+
+impl Display for Catalog {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for metadata in &self.entries {
+            writeln!(
+                f,
+                "{year}/{day:0>2}: {title} [{completion}]",
+                year = metadata.year,
+                day = metadata.day,
+                title = metadata.title,
+                completion = metadata.completion.label()
+            )?;
+
+            for (idx, label) in metadata.parts.iter().enumerate() {
+                writeln!(f, "  Part {part}: {label}", part = idx + 1)?;
+            }
+        }
+
+        if !self.entries.is_empty() {
+            writeln!(f)?;
+        }
+
+        for year in self.years() {
+            writeln!(f, "{}", self.progress_line(year))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,404 @@
+use crate::config::{ClientConfig, PuzzleFiles};
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::Duration;
+use ureq::Agent;
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub enum Verdict {
+    Accepted,
+    Rejected(Option<String>),
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn parse(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            return Self::Accepted;
+        }
+
+        if body.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| body.contains(&format!("your answer is {hint}")))
+                .map(str::to_string);
+
+            return Self::Rejected(hint);
+        }
+
+        if body.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(?<minutes>\d+)m )?(?<seconds>\d+)s left to wait")
+                .unwrap()
+                .captures(body)
+                .map(|caps| {
+                    let minutes = caps
+                        .name("minutes")
+                        .map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                    let seconds = caps["seconds"].parse().unwrap_or(0);
+
+                    Duration::from_secs(minutes * 60 + seconds)
+                });
+
+            return Self::RateLimited(wait);
+        }
+
+        if body.contains("You don't seem to be solving the right level") {
+            return Self::AlreadySolved;
+        }
+
+        Self::Unknown(Self::article(body))
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Rejected(_) => "rejected",
+            Self::RateLimited(_) => "rate limited",
+            Self::AlreadySolved => "already solved",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Accepted | Self::AlreadySolved)
+    }
+
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::Rejected(hint) => hint.clone(),
+            Self::RateLimited(wait) => wait.map(|wait| format!("wait {wait:?}")),
+            Self::Unknown(message) => Some(message.clone()).filter(|message| !message.is_empty()),
+            Self::Accepted | Self::AlreadySolved => None,
+        }
+    }
+
+    fn article(body: &str) -> String {
+        let text = Regex::new(r"(?s)<article>(?<text>.*?)</article>")
+            .unwrap()
+            .captures(body)
+            .map_or(body, |caps| caps.name("text").unwrap().as_str());
+
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(text, "");
+
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.label().to_uppercase())?;
+
+        match self.detail() {
+            Some(detail) => write!(f, " ({detail})"),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    config: ClientConfig,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .build()
+            .into();
+
+        Self { agent, config }
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u16) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.config.base_url);
+
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie()?)
+            .call()?;
+
+        let body = response.body_mut().read_to_string()?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "fetching {url} failed with {}: {}",
+                response.status(),
+                body.trim()
+            );
+        }
+
+        Ok(body)
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u16,
+        part: usize,
+        answer: &str,
+    ) -> anyhow::Result<Verdict> {
+        let url = format!("{}/{year}/day/{day}/answer", self.config.base_url);
+        let level = part.to_string();
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie()?)
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+
+        let body = response.body_mut().read_to_string()?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("submitting to {url} failed with {}", response.status());
+        }
+
+        Ok(Verdict::parse(&body))
+    }
+
+    fn cookie(&self) -> anyhow::Result<String> {
+        let Some(session) = &self.config.session else {
+            anyhow::bail!("no session token given (use --session or AOC_SESSION)");
+        };
+
+        Ok(format!("session={session}"))
+    }
+}
+
+pub struct Fetch {
+    pub year: u16,
+    pub day: u16,
+    pub path: PathBuf,
+    pub cached: bool,
+}
+
+impl Fetch {
+    pub fn run(client: &Client, year: u16, day: u16, force: bool) -> anyhow::Result<Self> {
+        Self::run_in(client, PuzzleFiles::new(year, day), year, day, force)
+    }
+
+    fn run_in(
+        client: &Client,
+        files: PuzzleFiles,
+        year: u16,
+        day: u16,
+        force: bool,
+    ) -> anyhow::Result<Self> {
+        let path = files.input_path();
+        let cached = !force && path.exists();
+
+        if !cached {
+            files.write_input(&client.fetch_input(year, day)?)?;
+        }
+
+        Ok(Self {
+            year,
+            day,
+            path,
+            cached,
+        })
+    }
+}
+
+impl Display for Fetch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let action = if self.cached { "cached" } else { "fetched" };
+
+        writeln!(
+            f,
+            "{year}/{day:0>2}: {action} {path}",
+            year = self.year,
+            day = self.day,
+            path = self.path.display()
+        )
+    }
+}
+
+pub struct Submission {
+    pub year: u16,
+    pub day: u16,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{year}/{day:0>2} part {part}: {answer} {verdict}",
+            year = self.year,
+            day = self.day,
+            part = self.part,
+            answer = self.answer,
+            verdict = self.verdict
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::{env, fs, process, thread};
+
+    #[rstest]
+    #[case(
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        "ACCEPTED"
+    )]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        "REJECTED (too high)"
+    )]
+    #[case("<article><p>That's not the right answer.</p></article>", "REJECTED")]
+    #[case(
+        "<article><p>You gave an answer too recently. You have 4m 12s left to wait.</p></article>",
+        "RATE LIMITED (wait 252s)"
+    )]
+    #[case(
+        "<article><p>You gave an answer too recently. You have 35s left to wait.</p></article>",
+        "RATE LIMITED (wait 35s)"
+    )]
+    #[case(
+        "<article><p>You don't seem to be solving the right level.</p></article>",
+        "ALREADY SOLVED"
+    )]
+    #[case(
+        "<html><article><p>Something <em>else</em>.</p></article></html>",
+        "UNKNOWN (Something else.)"
+    )]
+    fn verdict_tests(#[case] body: &str, #[case] expected: &str) {
+        assert_eq!(Verdict::parse(body).to_string(), expected);
+    }
+
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+
+                request.push_str(&line);
+
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn client(base_url: String) -> Client {
+        Client::new(ClientConfig {
+            base_url,
+            session: Some(String::from("secret")),
+        })
+    }
+
+    #[test]
+    fn fetch_input_from_stub_server() {
+        let (base_url, server) = serve_once("(()))\n");
+
+        let input = client(base_url).fetch_input(2015, 1).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "(()))\n");
+        assert!(request.starts_with("GET /2015/day/1/input "));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn submit_answer_to_stub_server() {
+        let (base_url, server) = serve_once("<article><p>That's the right answer!</p></article>");
+
+        let verdict = client(base_url).submit(2015, 1, 2, "5").unwrap();
+        let request = server.join().unwrap();
+
+        assert!(matches!(verdict, Verdict::Accepted));
+        assert!(request.starts_with("POST /2015/day/1/answer "));
+        assert!(request.ends_with("level=2&answer=5"));
+    }
+
+    #[test]
+    fn fetch_reuses_cached_input() {
+        let dir = env::temp_dir().join(format!("aoc_fetch_cache_{}", process::id()));
+        let (base_url, server) = serve_once("(()))\n");
+        let client = client(base_url);
+
+        let first =
+            Fetch::run_in(&client, PuzzleFiles::in_dir(dir.clone()), 2015, 1, false).unwrap();
+        server.join().unwrap();
+
+        // The stub server is gone, so another request would fail to connect.
+        let second =
+            Fetch::run_in(&client, PuzzleFiles::in_dir(dir.clone()), 2015, 1, false).unwrap();
+        let input = fs::read_to_string(&second.path).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!first.cached);
+        assert!(second.cached);
+        assert_eq!(second.path, first.path);
+        assert_eq!(input, "(()))\n");
+    }
+
+    #[test]
+    fn fetch_with_force_ignores_cache() {
+        let dir = env::temp_dir().join(format!("aoc_fetch_force_{}", process::id()));
+        PuzzleFiles::in_dir(dir.clone())
+            .write_input("stale")
+            .unwrap();
+
+        let (base_url, server) = serve_once("fresh");
+        let fetch = Fetch::run_in(
+            &client(base_url),
+            PuzzleFiles::in_dir(dir.clone()),
+            2015,
+            1,
+            true,
+        )
+        .unwrap();
+        server.join().unwrap();
+
+        let input = fs::read_to_string(&fetch.path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!fetch.cached);
+        assert_eq!(input, "fresh");
+    }
+}
@@ -1,19 +1,104 @@
-use anyhow::Context;
-use std::fs;
-use std::path::PathBuf;
+use crate::error::Error;
+use crate::format::Format;
+use crate::settings::{Settings, settings};
+use std::io::Read;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+const DATA_DIR: &str = "test_data";
+const DATA_DIR_VARIABLE: &str = "AOC_DATA_DIR";
+const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const STDIN_FILE_PATH: &str = "-";
+const INPUT_FILE: &str = "input.txt";
+const SOLUTION_FILE: &str = "solution.txt";
 
 #[derive(clap::Parser)]
 pub struct Args {
+    #[clap(subcommand)]
+    pub command: Option<Command>,
     #[clap(short)]
-    pub year: u16,
+    pub year: Option<u16>,
     #[clap(short)]
-    pub day: u16,
+    pub day: Option<u16>,
     #[clap(flatten)]
     pub input: InputArgs,
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    #[clap(short, long, requires = "day")]
+    pub check: bool,
+    #[clap(short, long, requires = "day", conflicts_with = "check")]
+    pub watch: bool,
+    #[clap(short, long)]
+    pub timings: bool,
+    #[clap(long, requires = "day")]
+    pub trace: bool,
+    #[clap(short, long)]
+    pub jobs: Option<NonZeroUsize>,
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+    #[clap(long)]
+    pub no_history: bool,
+    #[clap(long, value_enum, global = true)]
+    pub format: Option<Format>,
+    #[clap(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+}
+
+impl Args {
+    /// Fills in what the flags leave open from the configuration files.
+    pub fn with_config(self) -> anyhow::Result<Self> {
+        let settings = Settings::discover(self.config.as_deref())?;
+        let args = self.with_settings(&settings);
+
+        settings.install()?;
+
+        Ok(args)
+    }
+
+    pub(crate) fn with_settings(mut self, settings: &Settings) -> Self {
+        self.year = self.year.or(settings.year);
+        self.format = self.format.or(settings.format);
+        self.jobs = self.jobs.or(settings.jobs);
+
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            let overrides = settings.day(year, day);
+
+            self.part = self.part.or(overrides.part);
+
+            if self.input.input.is_none() && self.input.file_path.is_none() {
+                self.input.file_path = overrides.file.map(|file| file.display().to_string());
+            }
+        }
+
+        self
+    }
+}
+
+#[derive(clap::Subcommand)]
+pub enum Command {
+    List(ListArgs),
+    New(NewArgs),
+    Bench(BenchArgs),
+    Examples(ExamplesArgs),
+    History(HistoryArgs),
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+}
+
+#[derive(clap::Args)]
+pub struct ListArgs {
+    #[clap(short)]
+    pub year: Option<u16>,
+    #[clap(long, value_name = "PATH")]
+    pub readme: Option<PathBuf>,
 }
 
 #[derive(clap::Args)]
-#[group(required = true, multiple = false)]
+#[group(multiple = false, requires = "day")]
 pub struct InputArgs {
     #[clap(short)]
     pub input: Option<String>,
@@ -21,21 +106,233 @@ pub struct InputArgs {
     pub file_path: Option<String>,
 }
 
+#[derive(clap::Args)]
+pub struct NewArgs {
+    #[clap(short)]
+    pub year: u16,
+    #[clap(short, value_parser = clap::value_parser!(u16).range(1..=25))]
+    pub day: u16,
+    #[clap(long)]
+    pub title: Option<String>,
+}
+
+#[derive(clap::Args)]
+pub struct BenchArgs {
+    #[clap(short)]
+    pub year: u16,
+    #[clap(short)]
+    pub day: Option<u16>,
+    #[clap(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+    #[clap(long, value_name = "PATH")]
+    pub baseline: Option<PathBuf>,
+    #[clap(long)]
+    pub save: bool,
+    #[clap(long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+#[derive(clap::Args)]
+pub struct ExamplesArgs {
+    #[clap(short)]
+    pub year: Option<u16>,
+    #[clap(short, requires = "year")]
+    pub day: Option<u16>,
+}
+
+#[derive(clap::Args)]
+pub struct HistoryArgs {
+    #[clap(short)]
+    pub year: Option<u16>,
+    #[clap(short, requires = "year")]
+    pub day: Option<u16>,
+}
+
+#[derive(clap::Args)]
+pub struct ClientArgs {
+    #[clap(long, value_name = "URL")]
+    pub base_url: Option<String>,
+    #[clap(long, value_name = "TOKEN")]
+    pub session: Option<String>,
+}
+
+#[derive(clap::Args)]
+pub struct FetchArgs {
+    #[clap(short)]
+    pub year: u16,
+    #[clap(short, value_parser = clap::value_parser!(u16).range(1..=25))]
+    pub day: u16,
+    #[clap(long)]
+    pub force: bool,
+    #[clap(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(clap::Args)]
+pub struct SubmitArgs {
+    #[clap(short)]
+    pub year: u16,
+    #[clap(short, value_parser = clap::value_parser!(u16).range(1..=25))]
+    pub day: u16,
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+    pub answer: Option<String>,
+    #[clap(flatten)]
+    pub client: ClientArgs,
+}
+
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl ClientArgs {
+    pub fn resolve(self) -> ClientConfig {
+        let variable = |name| env::var(name).ok().filter(|value| !value.is_empty());
+
+        let base_url = self
+            .base_url
+            .or_else(|| variable(BASE_URL_VARIABLE))
+            .or_else(|| settings().base_url.clone())
+            .unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+
+        let session = self
+            .session
+            .or_else(|| variable(SESSION_VARIABLE))
+            .or_else(|| settings().session.clone());
+
+        ClientConfig {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+}
+
 impl InputArgs {
-    pub fn extract(self) -> anyhow::Result<String> {
+    pub fn extract(self, year: u16, day: u16) -> anyhow::Result<String> {
         let input = match (&self.file_path, self.input) {
             (None, Some(input)) => input,
 
-            (Some(file_path), None) => {
-                let path = PathBuf::from(file_path);
+            (Some(file_path), None) if file_path == STDIN_FILE_PATH => {
+                let mut input = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::MissingInput {
+                        path: String::from("stdin"),
+                        source,
+                    })?;
 
-                fs::read_to_string(&path)
-                    .with_context(|| format!("unable to read {file_path} input file"))?
+                input
             }
 
+            (Some(file_path), None) => PuzzleFiles::read(PathBuf::from(file_path))?,
+
+            (None, None) => PuzzleFiles::new(year, day).read_input()?,
+
             _ => anyhow::bail!("invalid input parameters"),
         };
 
         Ok(input)
     }
+
+    pub fn watch_path(&self, year: u16, day: u16) -> anyhow::Result<PathBuf> {
+        let path = match (&self.file_path, &self.input) {
+            (Some(file_path), None) if file_path == STDIN_FILE_PATH => {
+                anyhow::bail!("cannot watch stdin")
+            }
+            (Some(file_path), None) => PathBuf::from(file_path),
+            (None, Some(_)) => anyhow::bail!("cannot watch inline input"),
+            (None, None) => PuzzleFiles::new(year, day).input_path(),
+            _ => anyhow::bail!("invalid input parameters"),
+        };
+
+        Ok(path)
+    }
+}
+
+pub struct PuzzleFiles {
+    dir: PathBuf,
+}
+
+impl PuzzleFiles {
+    pub fn new(year: u16, day: u16) -> Self {
+        let layout = settings()
+            .layout()
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &format!("{day:0>2}"));
+
+        Self::in_dir(Self::data_dir().join(layout))
+    }
+
+    /// Files kept directly in `dir`, bypassing the configured data directory and layout.
+    pub fn in_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn data_dir() -> PathBuf {
+        env::var_os(DATA_DIR_VARIABLE)
+            .map(PathBuf::from)
+            .or_else(|| settings().data_dir.clone())
+            .unwrap_or_else(|| PathBuf::from(DATA_DIR))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.dir.join(INPUT_FILE)
+    }
+
+    pub fn read_input(&self) -> Result<String, Error> {
+        Self::read(self.input_path())
+    }
+
+    pub fn write_input(&self, input: &str) -> anyhow::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.input_path(), input)?;
+
+        Ok(())
+    }
+
+    pub fn read_solution(&self) -> Result<Option<String>, Error> {
+        let path = self.dir.join(SOLUTION_FILE);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        Self::read(path).map(Some)
+    }
+
+    /// Stores an accepted answer, as long as the preceding parts are already recorded.
+    pub fn record_solution(&self, part: usize, answer: &str) -> anyhow::Result<bool> {
+        let solution = self.read_solution()?.unwrap_or_default();
+        let mut lines = solution.lines().map(str::to_string).collect::<Vec<_>>();
+
+        match lines.len() {
+            len if len >= part => lines[part - 1] = answer.to_string(),
+            len if len == part - 1 => lines.push(answer.to_string()),
+            _ => return Ok(false),
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(SOLUTION_FILE), lines.join("\n"))?;
+
+        Ok(true)
+    }
+
+    fn read(path: PathBuf) -> Result<String, Error> {
+        fs::read_to_string(&path).map_err(|source| Error::MissingInput {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value.parse().map_err(|error| format!("{error}"))?;
+
+    Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
 }
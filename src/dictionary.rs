@@ -1,12 +1,12 @@
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub type DictionaryIdx = usize;
 
 #[derive(Default, Debug)]
 pub struct Dictionary {
-    str_to_idx: HashMap<Rc<str>, DictionaryIdx>,
-    idx_to_str: Vec<Rc<str>>,
+    str_to_idx: HashMap<Arc<str>, DictionaryIdx>,
+    idx_to_str: Vec<Arc<str>>,
 }
 
 impl Dictionary {
@@ -22,7 +22,7 @@ impl Dictionary {
         if let Some(idx) = self.map_to_idx(name) {
             idx
         } else {
-            let name: Rc<str> = Rc::from(name);
+            let name: Arc<str> = Arc::from(name);
             let idx = self.idx_to_str.len();
 
             self.idx_to_str.push(name.clone());
@@ -35,4 +35,8 @@ impl Dictionary {
     pub fn len(&self) -> usize {
         self.idx_to_str.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.idx_to_str.is_empty()
+    }
 }
@@ -0,0 +1,110 @@
+use regex::Captures;
+use std::fmt::Display;
+use std::io;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("invalid input at line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    #[error("puzzle could not be solved: {0}")]
+    Unsolvable(String),
+
+    #[error("unable to read {path}")]
+    MissingInput {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("no solver found for: year {year}, day {day}")]
+    NoSolver { year: u16, day: u16 },
+
+    #[error("timed out")]
+    TimedOut,
+}
+
+impl Error {
+    pub fn parse(column: usize, message: impl Display) -> Self {
+        Self::Parse {
+            line: 1,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Display) -> Self {
+        Self::Unsolvable(message.to_string())
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Self::Parse {
+                column, message, ..
+            } => Self::Parse {
+                line,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn at_column(self, column: usize) -> Self {
+        match self {
+            Self::Parse { line, message, .. } => Self::Parse {
+                line,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::MissingInput { .. } => 3,
+            Self::Parse { .. } => 4,
+            Self::Unsolvable(_) => 5,
+            Self::NoSolver { .. } => 6,
+            Self::TimedOut => 7,
+        }
+    }
+}
+
+pub trait ParseContext<T> {
+    fn at_line(self, line: usize) -> Result<T, Error>;
+
+    fn at_column(self, column: usize) -> Result<T, Error>;
+}
+
+impl<T> ParseContext<T> for Result<T, Error> {
+    fn at_line(self, line: usize) -> Result<T, Error> {
+        self.map_err(|error| error.at_line(line))
+    }
+
+    fn at_column(self, column: usize) -> Result<T, Error> {
+        self.map_err(|error| error.at_column(column))
+    }
+}
+
+pub fn parse_capture<T>(caps: &Captures, name: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let Some(capture) = caps.name(name) else {
+        return Err(Error::parse(1, format!("missing '{name}'")));
+    };
+
+    capture
+        .as_str()
+        .parse()
+        .map_err(|error| Error::parse(capture.start() + 1, format!("invalid {name}: {error}")))
+}
@@ -0,0 +1,681 @@
+use crate::Output;
+use crate::bench::{BenchReport, Measurement};
+use crate::catalog::Catalog;
+use crate::client::{Fetch, Submission};
+use crate::history::{HistoryReport, format_timestamp};
+use crate::puzzle::Answer;
+use crate::report::{Entry, Mismatch, Report, Status};
+use crate::scaffold::Scaffold;
+use itertools::Itertools;
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const TSV_HEADER: &str = "year\tday\tpart\tresult\texpected\tstatus\telapsed_ms\terror\texample";
+const TSV_CATALOG_HEADER: &str = "year\tday\ttitle\tcompletion\tparts";
+const TSV_SCAFFOLD_HEADER: &str = "action\tpath";
+const TSV_BENCH_HEADER: &str =
+    "year\tday\tstage\truns\tmin_ms\tmedian_ms\tp95_ms\tbaseline_ms\tregression\terror";
+const TSV_FETCH_HEADER: &str = "year\tday\taction\tpath";
+const TSV_SUBMISSION_HEADER: &str = "year\tday\tpart\tanswer\tverdict\tdetail";
+const TSV_HISTORY_HEADER: &str = "time\tyear\tday\tinput\tstatus\tpart\tresult\tprevious";
+
+#[derive(Clone, Copy, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+impl Format {
+    pub fn render(self, output: &Output, timings: bool) -> String {
+        match self {
+            Self::Text if timings => format!("{output:#}"),
+            Self::Text => output.to_string(),
+            Self::Json => {
+                let value = match output {
+                    Output::Answer(answer) => Json::answer(answer),
+                    Output::Check(entry) => Json::entry(entry),
+                    Output::Report(report) => Json::report(report),
+                    Output::Catalog(catalog) => Json::catalog(catalog),
+                    Output::Scaffold(scaffold) => Json::scaffold(scaffold),
+                    Output::Bench(report) => Json::bench(report),
+                    Output::History(report) => Json::history(report),
+                    Output::Fetch(fetch) => Json::fetch(fetch),
+                    Output::Submission(submission) => Json::submission(submission),
+                };
+
+                serde_json::to_string_pretty(&value).unwrap_or_default()
+            }
+            Self::Tsv => {
+                let (header, rows) = match output {
+                    Output::Answer(answer) => (TSV_HEADER, Tsv::answer(answer)),
+                    Output::Check(entry) => (TSV_HEADER, Tsv::entry(entry)),
+                    Output::Report(report) => (TSV_HEADER, Tsv::report(report)),
+                    Output::Catalog(catalog) => (TSV_CATALOG_HEADER, Tsv::catalog(catalog)),
+                    Output::Scaffold(scaffold) => (TSV_SCAFFOLD_HEADER, Tsv::scaffold(scaffold)),
+                    Output::Bench(report) => (TSV_BENCH_HEADER, Tsv::bench(report)),
+                    Output::History(report) => (TSV_HISTORY_HEADER, Tsv::history(report)),
+                    Output::Fetch(fetch) => (TSV_FETCH_HEADER, Tsv::fetch(fetch)),
+                    Output::Submission(submission) => {
+                        (TSV_SUBMISSION_HEADER, Tsv::submission(submission))
+                    }
+                };
+
+                [header.to_string()].into_iter().chain(rows).join("\n")
+            }
+        }
+    }
+
+    /// Renders an error that ended a puzzle run in place of its output, except
+    /// as text, where it goes to standard error instead.
+    pub fn render_error(
+        self,
+        year: Option<u16>,
+        day: Option<u16>,
+        error: &anyhow::Error,
+    ) -> Option<String> {
+        match self {
+            Self::Text => None,
+            Self::Json => serde_json::to_string_pretty(&Json::failure(year, day, error)).ok(),
+            Self::Tsv => Some(format!("{TSV_HEADER}\n{}", Tsv::failure(year, day, error))),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+struct Json;
+
+impl Json {
+    fn answer(answer: &Answer) -> Value {
+        let parts = answer
+            .parts()
+            .map(|(part, result, elapsed)| {
+                json!({
+                    "part": part,
+                    "result": result,
+                    "elapsed_ms": elapsed.map(millis),
+                })
+            })
+            .collect_vec();
+
+        json!({
+            "year": answer.year,
+            "day": answer.day,
+            "parts": parts,
+            "parse_ms": answer.timings.parse.map(millis),
+            "total_ms": millis(answer.timings.total),
+            "trace": Some(&answer.trace).filter(|trace| !trace.is_empty()),
+        })
+    }
+
+    fn failure(year: Option<u16>, day: Option<u16>, error: &anyhow::Error) -> Value {
+        json!({
+            "year": year,
+            "day": day,
+            "parts": [],
+            "parse_ms": null,
+            "total_ms": null,
+            "trace": null,
+            "example": null,
+            "status": Status::ERROR,
+            "error": format!("{error:#}"),
+            "mismatches": [],
+        })
+    }
+
+    fn entry(entry: &Entry) -> Value {
+        let mut value = match &entry.answer {
+            Some(answer) => Self::answer(answer),
+            None => json!({
+                "year": entry.year,
+                "day": entry.day,
+                "parts": [],
+                "parse_ms": null,
+                "total_ms": null,
+                "trace": null,
+            }),
+        };
+
+        let (mismatches, error) = match &entry.status {
+            Status::Fail(mismatches) => (mismatches.as_slice(), None),
+            Status::Error(error) => (&[][..], Some(format!("{error:#}"))),
+            _ => (&[][..], None),
+        };
+
+        value["example"] = json!(entry.example);
+        value["status"] = json!(entry.status.label());
+        value["error"] = json!(error);
+        value["mismatches"] = mismatches
+            .iter()
+            .map(|mismatch| {
+                json!({
+                    "part": mismatch.part,
+                    "expected": mismatch.expected,
+                    "actual": mismatch.actual,
+                })
+            })
+            .collect();
+
+        value
+    }
+
+    fn report(report: &Report) -> Value {
+        let summary = report.summary();
+
+        json!({
+            "entries": report.entries().iter().map(Self::entry).collect_vec(),
+            "summary": {
+                "passed": summary.passed,
+                "failed": summary.failed,
+                "errors": summary.errors,
+                "timed_out": summary.timed_out,
+                "unverified": summary.unverified,
+            },
+        })
+    }
+
+    fn catalog(catalog: &Catalog) -> Value {
+        let entries = catalog
+            .entries()
+            .iter()
+            .map(|metadata| {
+                json!({
+                    "year": metadata.year,
+                    "day": metadata.day,
+                    "title": metadata.title,
+                    "parts": metadata.parts,
+                    "completion": metadata.completion.label(),
+                })
+            })
+            .collect_vec();
+
+        json!({ "entries": entries })
+    }
+
+    fn scaffold(scaffold: &Scaffold) -> Value {
+        let paths = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect_vec()
+        };
+
+        json!({
+            "created": paths(scaffold.created()),
+            "updated": paths(scaffold.updated()),
+        })
+    }
+
+    fn bench(report: &BenchReport) -> Value {
+        let benchmarks = report
+            .benchmarks
+            .iter()
+            .map(|benchmark| {
+                let measurements = benchmark
+                    .measurements
+                    .iter()
+                    .map(|measurement| Self::measurement(measurement, report.threshold))
+                    .collect_vec();
+
+                json!({
+                    "year": benchmark.year,
+                    "day": benchmark.day,
+                    "runs": benchmark.runs,
+                    "measurements": measurements,
+                })
+            })
+            .collect_vec();
+
+        let failures = report
+            .failures
+            .iter()
+            .map(|failure| {
+                json!({
+                    "year": failure.year,
+                    "day": failure.day,
+                    "error": format!("{:#}", failure.error),
+                })
+            })
+            .collect_vec();
+
+        json!({
+            "benchmarks": benchmarks,
+            "failures": failures,
+            "regressions": report.regressions(),
+        })
+    }
+
+    fn history(report: &HistoryReport) -> Value {
+        let entries = report
+            .changes()
+            .into_iter()
+            .map(|(record, previous)| {
+                let parts = record
+                    .parts(previous)
+                    .map(|(part, result, previous)| {
+                        json!({
+                            "part": part,
+                            "result": result,
+                            "previous": previous,
+                        })
+                    })
+                    .collect_vec();
+
+                json!({
+                    "timestamp": record.timestamp,
+                    "year": record.year,
+                    "day": record.day,
+                    "input_hash": record.input_hash.to_string(),
+                    "status": record.status,
+                    "parts": parts,
+                })
+            })
+            .collect_vec();
+
+        json!({ "entries": entries })
+    }
+
+    fn fetch(fetch: &Fetch) -> Value {
+        json!({
+            "year": fetch.year,
+            "day": fetch.day,
+            "path": fetch.path.display().to_string(),
+            "cached": fetch.cached,
+        })
+    }
+
+    fn submission(submission: &Submission) -> Value {
+        json!({
+            "year": submission.year,
+            "day": submission.day,
+            "part": submission.part,
+            "answer": submission.answer,
+            "verdict": submission.verdict.label(),
+            "detail": submission.verdict.detail(),
+        })
+    }
+
+    fn measurement(measurement: &Measurement, threshold: f64) -> Value {
+        json!({
+            "stage": measurement.stage.key(),
+            "min_ms": millis(measurement.stats.min),
+            "median_ms": millis(measurement.stats.median),
+            "p95_ms": millis(measurement.stats.p95),
+            "baseline_ms": measurement.baseline.map(millis),
+            "regression": measurement.is_regression(threshold),
+        })
+    }
+}
+
+#[derive(Default)]
+struct TsvRow<'a> {
+    year: u16,
+    day: u16,
+    part: Option<usize>,
+    result: &'a str,
+    expected: &'a str,
+    status: &'a str,
+    elapsed: Option<Duration>,
+    error: String,
+    example: Option<usize>,
+}
+
+impl TsvRow<'_> {
+    fn render(&self) -> String {
+        let part = self.part.map(|part| part.to_string()).unwrap_or_default();
+        let example = self
+            .example
+            .map(|example| example.to_string())
+            .unwrap_or_default();
+
+        let elapsed = self
+            .elapsed
+            .map(|elapsed| format!("{:.3}", millis(elapsed)))
+            .unwrap_or_default();
+
+        let fields = [
+            &part,
+            self.result,
+            self.expected,
+            self.status,
+            &elapsed,
+            &self.error,
+            &example,
+        ];
+
+        fields
+            .iter()
+            .map(|field| field.replace(['\t', '\n'], " "))
+            .fold(format!("{}\t{}", self.year, self.day), |row, field| {
+                row + "\t" + &field
+            })
+    }
+}
+
+struct Tsv;
+
+impl Tsv {
+    fn answer(answer: &Answer) -> Vec<String> {
+        Self::part_rows(answer, &[], "", None)
+    }
+
+    fn entry(entry: &Entry) -> Vec<String> {
+        let status = entry.status.label();
+
+        match (&entry.answer, &entry.status) {
+            (Some(answer), Status::Fail(mismatches)) => {
+                Self::part_rows(answer, mismatches, status, entry.example)
+            }
+            (Some(answer), _) => Self::part_rows(answer, &[], status, entry.example),
+            (None, status_with_error) => {
+                let error = match status_with_error {
+                    Status::Error(error) => format!("{error:#}"),
+                    _ => String::new(),
+                };
+
+                let row = TsvRow {
+                    year: entry.year,
+                    day: entry.day,
+                    status,
+                    error,
+                    example: entry.example,
+                    ..Default::default()
+                };
+
+                vec![row.render()]
+            }
+        }
+    }
+
+    fn report(report: &Report) -> Vec<String> {
+        report.entries().iter().flat_map(Self::entry).collect()
+    }
+
+    fn failure(year: Option<u16>, day: Option<u16>, error: &anyhow::Error) -> String {
+        let field = |value: Option<u16>| value.map(|value| value.to_string()).unwrap_or_default();
+        let error = format!("{error:#}").replace(['\t', '\n'], " ");
+
+        format!(
+            "{}\t{}\t\t\t\t{}\t\t{error}\t",
+            field(year),
+            field(day),
+            Status::ERROR
+        )
+    }
+
+    fn catalog(catalog: &Catalog) -> Vec<String> {
+        catalog
+            .entries()
+            .iter()
+            .map(|metadata| {
+                [
+                    metadata.year.to_string(),
+                    metadata.day.to_string(),
+                    metadata.title.to_string(),
+                    metadata.completion.label().to_string(),
+                    metadata.parts.join("; "),
+                ]
+                .iter()
+                .map(|field| field.replace(['\t', '\n'], " "))
+                .join("\t")
+            })
+            .collect()
+    }
+
+    fn scaffold(scaffold: &Scaffold) -> Vec<String> {
+        let created = scaffold.created().iter().map(|path| ("created", path));
+        let updated = scaffold.updated().iter().map(|path| ("updated", path));
+
+        created
+            .chain(updated)
+            .map(|(action, path)| format!("{action}\t{}", path.display()))
+            .collect()
+    }
+
+    fn bench(report: &BenchReport) -> Vec<String> {
+        let format_millis = |duration: Duration| format!("{:.3}", millis(duration));
+
+        let measurements = report.benchmarks.iter().flat_map(|benchmark| {
+            benchmark.measurements.iter().map(move |measurement| {
+                let baseline = measurement.baseline.map(format_millis).unwrap_or_default();
+
+                [
+                    benchmark.year.to_string(),
+                    benchmark.day.to_string(),
+                    measurement.stage.key(),
+                    benchmark.runs.to_string(),
+                    format_millis(measurement.stats.min),
+                    format_millis(measurement.stats.median),
+                    format_millis(measurement.stats.p95),
+                    baseline,
+                    measurement.is_regression(report.threshold).to_string(),
+                    String::new(),
+                ]
+                .join("\t")
+            })
+        });
+
+        let failures = report.failures.iter().map(|failure| {
+            let error = format!("{:#}", failure.error).replace(['\t', '\n'], " ");
+
+            format!("{}\t{}\t\t\t\t\t\t\t\t{error}", failure.year, failure.day)
+        });
+
+        measurements.chain(failures).collect()
+    }
+
+    fn history(report: &HistoryReport) -> Vec<String> {
+        report
+            .changes()
+            .into_iter()
+            .flat_map(|(record, previous)| {
+                record.parts(previous).map(move |(part, result, previous)| {
+                    [
+                        format_timestamp(record.timestamp),
+                        record.year.to_string(),
+                        record.day.to_string(),
+                        record.input_hash.to_string(),
+                        record.status.clone(),
+                        part.to_string(),
+                        result.to_string(),
+                        previous.unwrap_or_default().to_string(),
+                    ]
+                    .iter()
+                    .map(|field| field.replace(['\t', '\n'], " "))
+                    .join("\t")
+                })
+            })
+            .collect()
+    }
+
+    fn fetch(fetch: &Fetch) -> Vec<String> {
+        let action = if fetch.cached { "cached" } else { "fetched" };
+
+        vec![format!(
+            "{}\t{}\t{action}\t{}",
+            fetch.year,
+            fetch.day,
+            fetch.path.display()
+        )]
+    }
+
+    fn submission(submission: &Submission) -> Vec<String> {
+        let row = [
+            submission.year.to_string(),
+            submission.day.to_string(),
+            submission.part.to_string(),
+            submission.answer.clone(),
+            submission.verdict.label().to_string(),
+            submission.verdict.detail().unwrap_or_default(),
+        ]
+        .iter()
+        .map(|field| field.replace(['\t', '\n'], " "))
+        .join("\t");
+
+        vec![row]
+    }
+
+    fn part_rows(
+        answer: &Answer,
+        mismatches: &[Mismatch],
+        status: &str,
+        example: Option<usize>,
+    ) -> Vec<String> {
+        answer
+            .parts()
+            .map(|(part, result, elapsed)| {
+                let expected = mismatches
+                    .iter()
+                    .find(|mismatch| mismatch.part == part)
+                    .map(|mismatch| mismatch.expected.as_str())
+                    .unwrap_or_default();
+
+                let row = TsvRow {
+                    year: answer.year,
+                    day: answer.day,
+                    part: Some(part),
+                    result,
+                    expected,
+                    status,
+                    elapsed,
+                    error: String::new(),
+                    example,
+                };
+
+                row.render()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Mismatch;
+    use rstest::rstest;
+
+    fn answer(trace: &[&str]) -> Answer {
+        Answer {
+            year: 2015,
+            day: 1,
+            results: vec![String::from("3"), String::from("1")],
+            selected_part: None,
+            timings: Default::default(),
+            trace: trace.iter().map(|line| line.to_string()).collect(),
+            timed_out: Vec::new(),
+        }
+    }
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry::verify(answer(&[]), Some(String::from("3\n1"))),
+            Entry::verify(answer(&["step"]), Some(String::from("3\n2"))).for_example(1),
+            Entry::verify(answer(&[]), None),
+            Entry::error(2015, 2, anyhow::anyhow!("unable to read input")),
+        ]
+    }
+
+    fn keys(value: &Value) -> Vec<String> {
+        value
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn json_entries_share_schema() {
+        let expected = keys(&Json::failure(None, None, &anyhow::anyhow!("failed")));
+
+        for entry in entries() {
+            assert_eq!(keys(&Json::entry(&entry)), expected);
+        }
+    }
+
+    #[rstest]
+    #[case(&[], Value::Null)]
+    #[case(&["step 1", "step 2"], json!(["step 1", "step 2"]))]
+    fn json_trace_tests(#[case] trace: &[&str], #[case] expected: Value) {
+        assert_eq!(Json::answer(&answer(trace))["trace"], expected);
+    }
+
+    #[test]
+    fn json_error_entry() {
+        let value = Json::entry(&Entry::error(2015, 2, anyhow::anyhow!("unable to read")));
+
+        assert_eq!(value["status"], "error");
+        assert_eq!(value["error"], "unable to read");
+        assert_eq!(value["trace"], Value::Null);
+        assert_eq!(value["parts"], json!([]));
+    }
+
+    #[test]
+    fn json_mismatches() {
+        let value = Json::entry(&entries()[1]);
+
+        assert_eq!(value["status"], "fail");
+        assert_eq!(value["example"], 1);
+        assert_eq!(
+            value["mismatches"],
+            json!([{ "part": 2, "expected": "2", "actual": "1" }])
+        );
+    }
+
+    #[test]
+    fn tsv_rows_match_header() {
+        let columns = TSV_HEADER.split('\t').count();
+
+        let rows = entries()
+            .iter()
+            .flat_map(Tsv::entry)
+            .chain(Tsv::answer(&answer(&[])))
+            .chain([Tsv::failure(None, None, &anyhow::anyhow!("bad\tinput"))])
+            .collect_vec();
+
+        assert_eq!(rows.len(), 10);
+
+        for row in rows {
+            assert_eq!(row.split('\t').count(), columns, "{row:?}");
+        }
+    }
+
+    #[test]
+    fn tsv_fail_rows_show_expected_results() {
+        let mismatches = [Mismatch {
+            part: 2,
+            expected: String::from("2"),
+            actual: Some(String::from("1")),
+        }];
+
+        let rows = Tsv::part_rows(&answer(&[]), &mismatches, "fail", None);
+
+        assert_eq!(
+            rows,
+            [
+                "2015\t1\t1\t3\t\tfail\t\t\t",
+                "2015\t1\t2\t1\t2\tfail\t\t\t"
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Format::Text, None)]
+    #[case(Format::Json, Some("\"error\": \"no input\""))]
+    #[case(Format::Tsv, Some("2015\t\t\t\t\terror\t\tno input\t"))]
+    fn render_error_tests(#[case] format: Format, #[case] expected: Option<&str>) {
+        let rendered = format.render_error(Some(2015), None, &anyhow::anyhow!("no input"));
+
+        match expected {
+            Some(expected) => assert!(rendered.unwrap().contains(expected)),
+            None => assert!(rendered.is_none()),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::puzzle::{Session, Solver};
+use crate::registry;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use regex::{Captures, Regex};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+const SEED: u64 = 2015;
+const ARBITRARY_CASES: usize = 50;
+const MUTATED_CASES: usize = 25;
+const EXTREME_CASES: usize = 5;
+const MAX_LENGTH: usize = 40;
+const TIMEOUT: Duration = Duration::from_millis(10);
+
+const EDGE_CASES: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "\n\n",
+    "\"",
+    "\\",
+    "0",
+    "-1",
+    "a",
+    "[",
+    "{",
+    "[1.5]",
+    "\u{feff}",
+    "18446744073709551615\n1",
+    "99999999999999999999",
+    "-1\n-1",
+];
+
+const EXTREME_NUMBERS: &[&str] = &["0", "18446744073709551615", "99999999999999999999"];
+
+const INVALID_INPUTS: &[&str] = &["", " ", "\n", "\n\n", "\u{feff}", "%%%", "%%%\n???"];
+
+/// Puzzles whose input is free-form text, so that no line is malformed.
+const FREE_FORM: &[(u16, u16)] = &[(2015, 4), (2015, 5)];
+
+const FRAGMENTS: &[&str] = &[
+    "0",
+    "1",
+    "9",
+    "-1",
+    "18446744073709551615",
+    "99999999999999999999",
+    "-",
+    "x",
+    "a",
+    "z",
+    "A",
+    "e",
+    "H",
+    "O",
+    " ",
+    "\n",
+    "\t",
+    "\"",
+    "\\",
+    "\\x",
+    "(",
+    ")",
+    "[",
+    "]",
+    "{",
+    "}",
+    ",",
+    ":",
+    "=",
+    "->",
+    "=>",
+    "#",
+    ".",
+    "^",
+    "v",
+    "<",
+    ">",
+    "é",
+    "red",
+    "to",
+    "AND",
+    "OR",
+    "NOT",
+    "LSHIFT",
+    "turn on",
+    "toggle",
+    " through ",
+    "would gain",
+    "happiness units by sitting next to",
+    "can fly",
+    "capacity",
+    "Sue 1:",
+    "children: ",
+];
+
+fn arbitrary_input(rng: &mut StdRng) -> String {
+    let length = rng.random_range(0..MAX_LENGTH);
+
+    (0..length)
+        .map(|_| *FRAGMENTS.choose(rng).unwrap())
+        .collect()
+}
+
+fn mutated_input(rng: &mut StdRng, example: &str) -> String {
+    let mut chars = example.chars().collect_vec();
+
+    for _ in 0..rng.random_range(1..=3) {
+        let position = rng.random_range(0..=chars.len());
+
+        match rng.random_range(0..4) {
+            0 if position < chars.len() => {
+                chars.remove(position);
+            }
+            1 if position < chars.len() => {
+                let fragment = FRAGMENTS.choose(rng).unwrap();
+                chars.splice(position..=position, fragment.chars());
+            }
+            2 => chars.truncate(position),
+            _ => {
+                let fragment = FRAGMENTS.choose(rng).unwrap();
+                chars.splice(position..position, fragment.chars());
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Replaces the numbers of an example with ones at the edges of the integer types.
+fn extreme_input(rng: &mut StdRng, example: &str) -> String {
+    Regex::new(r"\d+")
+        .unwrap()
+        .replace_all(example, |_: &Captures| {
+            *EXTREME_NUMBERS.choose(rng).unwrap()
+        })
+        .into_owned()
+}
+
+fn session() -> Session {
+    Session::start(None).with_timeout(Some(TIMEOUT))
+}
+
+fn panics(solver: &dyn Solver, input: &str) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(|| solver.solve_in(input, session()))).is_err()
+}
+
+fn is_blank(input: &str) -> bool {
+    input.trim_start_matches('\u{feff}').trim().is_empty()
+}
+
+#[test]
+fn solvers_never_panic() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut failures = Vec::new();
+
+    for (year, day) in registry::puzzles() {
+        let solver = registry::solver(year, day).unwrap();
+        let examples = solver.metadata().examples;
+
+        let mut inputs = EDGE_CASES
+            .iter()
+            .map(|input| input.to_string())
+            .collect_vec();
+
+        inputs.extend((0..ARBITRARY_CASES).map(|_| arbitrary_input(&mut rng)));
+
+        for example in examples {
+            inputs.extend((0..MUTATED_CASES).map(|_| mutated_input(&mut rng, example.input)));
+            inputs.extend((0..EXTREME_CASES).map(|_| extreme_input(&mut rng, example.input)));
+        }
+
+        for input in inputs {
+            if panics(solver.as_ref(), &input) {
+                failures.push(format!("{year}/{day:0>2}: {input:?}"));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "solvers panicked on:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn solvers_reject_invalid_input() {
+    let mut accepted = Vec::new();
+
+    for (year, day) in registry::puzzles() {
+        let solver = registry::solver(year, day).unwrap();
+
+        let inputs = INVALID_INPUTS
+            .iter()
+            .filter(|input| is_blank(input) || !FREE_FORM.contains(&(year, day)));
+
+        for input in inputs {
+            if solver.solve_in(input, session()).is_ok() {
+                accepted.push(format!("{year}/{day:0>2}: {input:?}"));
+            }
+        }
+    }
+
+    assert!(
+        accepted.is_empty(),
+        "solvers accepted invalid input:\n{}",
+        accepted.join("\n")
+    );
+}
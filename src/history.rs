@@ -0,0 +1,465 @@
+use crate::config::PuzzleFiles;
+use crate::puzzle::Answer;
+use crate::report::Status;
+use itertools::Itertools;
+use md5::{Digest, Md5};
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HISTORY_FILE: &str = "history.tsv";
+const SHORT_HASH_LENGTH: usize = 8;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputHash(String);
+
+impl InputHash {
+    pub fn of(input: &str) -> Self {
+        let digest = Md5::digest(input.as_bytes());
+
+        Self(digest.iter().map(|byte| format!("{byte:02x}")).join(""))
+    }
+
+    pub fn short(&self) -> &str {
+        &self.0[..SHORT_HASH_LENGTH.min(self.0.len())]
+    }
+}
+
+impl Display for InputHash {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub struct Record {
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u16,
+    pub input_hash: InputHash,
+    pub status: String,
+    pub results: Vec<Option<String>>,
+}
+
+impl Record {
+    pub fn is_known_good(&self) -> bool {
+        self.status == Status::Pass.label()
+    }
+
+    /// Lists the recorded parts along with the result of a preceding record.
+    pub fn parts<'a>(
+        &'a self,
+        previous: Option<&'a Record>,
+    ) -> impl Iterator<Item = (usize, &'a str, Option<&'a str>)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(move |(idx, result)| {
+                let previous = previous.and_then(|previous| previous.results.get(idx)?.as_deref());
+                Some((idx + 1, result.as_deref()?, previous))
+            })
+    }
+
+    fn is_same_puzzle(&self, year: u16, day: u16, input_hash: &InputHash) -> bool {
+        self.year == year && self.day == day && &self.input_hash == input_hash
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+
+        let (timestamp, year, day, input_hash, status) = fields.next_tuple()?;
+
+        let results = fields
+            .map(|result| Some(result.to_string()).filter(|result| !result.is_empty()))
+            .collect();
+
+        Some(Self {
+            timestamp: timestamp.parse().ok()?,
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            input_hash: InputHash(input_hash.to_string()),
+            status: status.to_string(),
+            results,
+        })
+    }
+
+    fn render(&self) -> String {
+        let results = self.results.iter().map(|result| {
+            result
+                .as_deref()
+                .unwrap_or_default()
+                .replace(['\t', '\n'], " ")
+        });
+
+        [
+            self.timestamp.to_string(),
+            self.year.to_string(),
+            self.day.to_string(),
+            self.input_hash.to_string(),
+            self.status.clone(),
+        ]
+        .into_iter()
+        .chain(results)
+        .join("\t")
+    }
+}
+
+pub struct Conflict {
+    pub year: u16,
+    pub day: u16,
+    pub part: usize,
+    pub known_good: String,
+    pub actual: String,
+    pub input_hash: InputHash,
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{year}/{day:0>2} part {part}: {actual} disagrees with known-good {known_good} (input {input})",
+            year = self.year,
+            day = self.day,
+            part = self.part,
+            actual = self.actual,
+            known_good = self.known_good,
+            input = self.input_hash.short()
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct History {
+    path: Option<PathBuf>,
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let mut history = Self {
+            path: Some(path.to_path_buf()),
+            records: Vec::new(),
+        };
+
+        if !path.exists() {
+            return Ok(history);
+        }
+
+        for (idx, line) in fs::read_to_string(path)?.lines().enumerate() {
+            let Some(record) = Record::parse(line) else {
+                anyhow::bail!("invalid history entry at line {}: '{line}'", idx + 1);
+            };
+
+            history.records.push(record);
+        }
+
+        Ok(history)
+    }
+
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn records(&self, year: Option<u16>, day: Option<u16>) -> impl Iterator<Item = &Record> {
+        self.records.iter().filter(move |record| {
+            year.is_none_or(|year| year == record.year) && day.is_none_or(|day| day == record.day)
+        })
+    }
+
+    pub fn known_good(&self, year: u16, day: u16, input_hash: &InputHash) -> Option<&Record> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|record| record.is_same_puzzle(year, day, input_hash))
+            .find(|record| record.is_known_good())
+    }
+
+    pub fn record(
+        &mut self,
+        answer: &Answer,
+        status: &Status,
+        input_hash: &InputHash,
+    ) -> anyhow::Result<Vec<Conflict>> {
+        let results = answer
+            .results
+            .iter()
+            .enumerate()
+            .map(|(idx, result)| {
+                Some(result.clone()).filter(|_| {
+                    answer.is_selected(idx + 1) && !answer.timed_out.contains(&(idx + 1))
+                })
+            })
+            .collect_vec();
+
+        let conflicts = match self.known_good(answer.year, answer.day, input_hash) {
+            Some(known_good) => results
+                .iter()
+                .zip(&known_good.results)
+                .enumerate()
+                .filter_map(|(idx, pair)| match pair {
+                    (Some(actual), Some(expected)) if actual != expected => Some(Conflict {
+                        year: answer.year,
+                        day: answer.day,
+                        part: idx + 1,
+                        known_good: expected.clone(),
+                        actual: actual.clone(),
+                        input_hash: input_hash.clone(),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let record = Record {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            year: answer.year,
+            day: answer.day,
+            input_hash: input_hash.clone(),
+            status: status.label().to_string(),
+            results,
+        };
+
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", record.render())?;
+        }
+
+        self.records.push(record);
+
+        Ok(conflicts)
+    }
+}
+
+pub struct HistoryReport {
+    pub history: History,
+    pub year: Option<u16>,
+    pub day: Option<u16>,
+}
+
+impl HistoryReport {
+    /// Pairs every record with the record preceding it for the same puzzle input.
+    pub fn changes(&self) -> Vec<(&Record, Option<&Record>)> {
+        let records = self.history.records(self.year, self.day).collect_vec();
+
+        records
+            .iter()
+            .enumerate()
+            .map(|(idx, record)| {
+                let previous = records[..idx]
+                    .iter()
+                    .rev()
+                    .find(|previous| {
+                        previous.is_same_puzzle(record.year, record.day, &record.input_hash)
+                    })
+                    .copied();
+
+                (*record, previous)
+            })
+            .sorted_by_key(|(record, _)| (record.year, record.day, record.input_hash.0.clone()))
+            .collect()
+    }
+}
+
+impl Display for HistoryReport {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let changes = self.changes();
+
+        let groups = changes
+            .iter()
+            .chunk_by(|(record, _)| (record.year, record.day, record.input_hash.clone()));
+
+        for ((year, day, input_hash), group) in &groups {
+            writeln!(
+                f,
+                "=== HISTORY {year}/{day:0>2} (input {}) ===",
+                input_hash.short()
+            )?;
+
+            for (record, previous) in group {
+                let results = record
+                    .parts(*previous)
+                    .map(|(part, result, previous)| match previous {
+                        Some(previous) if previous != result => {
+                            format!("Part {part}: {result} (was {previous})")
+                        }
+                        _ => format!("Part {part}: {result}"),
+                    })
+                    .join(", ");
+
+                writeln!(
+                    f,
+                    "{timestamp} {status:<10} {results}",
+                    timestamp = format_timestamp(record.timestamp),
+                    status = record.status.to_uppercase()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn default_history_path() -> PathBuf {
+    PuzzleFiles::data_dir().join(HISTORY_FILE)
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / SECONDS_PER_DAY) as i64;
+    let seconds = timestamp % SECONDS_PER_DAY;
+
+    let era_days = days + 719_468;
+    let era = era_days.div_euclid(146_097);
+    let day_of_era = era_days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}",
+        hour = seconds / 3600,
+        minute = seconds % 3600 / 60,
+        second = seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn answer(results: &[&str]) -> Answer {
+        Answer {
+            year: 2015,
+            day: 1,
+            results: results.iter().map(|result| result.to_string()).collect(),
+            selected_part: None,
+            timings: Default::default(),
+            trace: Vec::new(),
+            timed_out: Vec::new(),
+        }
+    }
+
+    #[rstest]
+    #[case("1700000000\t2015\t1\tabc\tpass\t74\t1795", &[Some("74"), Some("1795")])]
+    #[case("1700000000\t2015\t1\tabc\tunverified\t\t1795", &[None, Some("1795")])]
+    #[case("1700000000\t2015\t1\tabc\terror", &[])]
+    fn record_round_trip_tests(#[case] line: &str, #[case] expected_results: &[Option<&str>]) {
+        let record = Record::parse(line).unwrap();
+
+        assert_eq!(record.timestamp, 1_700_000_000);
+        assert_eq!((record.year, record.day), (2015, 1));
+        assert_eq!(record.input_hash.to_string(), "abc");
+        assert_eq!(
+            record.results.iter().map(Option::as_deref).collect_vec(),
+            expected_results
+        );
+        assert_eq!(record.render(), line);
+    }
+
+    #[test]
+    fn record_render_escapes_separators() {
+        let record = Record {
+            timestamp: 0,
+            year: 2015,
+            day: 8,
+            input_hash: InputHash::of(""),
+            status: String::from("pass"),
+            results: vec![Some(String::from("a\tb\nc")), None],
+        };
+
+        let parsed = Record::parse(&record.render()).unwrap();
+
+        assert_eq!(parsed.results, [Some(String::from("a b c")), None]);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("1700000000\t2015\t1\tabc")]
+    #[case("yesterday\t2015\t1\tabc\tpass\t74")]
+    #[case("1700000000\t2015\tone\tabc\tpass\t74")]
+    fn record_negative_tests(#[case] line: &str) {
+        assert!(Record::parse(line).is_none());
+    }
+
+    #[rstest]
+    #[case(0, "1970-01-01 00:00:00")]
+    #[case(86_399, "1970-01-01 23:59:59")]
+    #[case(946_684_799, "1999-12-31 23:59:59")]
+    #[case(951_782_400, "2000-02-29 00:00:00")]
+    #[case(951_868_800, "2000-03-01 00:00:00")]
+    #[case(1_735_689_599, "2024-12-31 23:59:59")]
+    #[case(4_107_542_400, "2100-03-01 00:00:00")]
+    fn format_timestamp_tests(#[case] timestamp: u64, #[case] expected: &str) {
+        assert_eq!(format_timestamp(timestamp), expected);
+    }
+
+    #[test]
+    fn record_reports_conflicts_with_last_pass() {
+        let input_hash = InputHash::of("(()))");
+        let mut history = History::disabled();
+
+        let conflicts = history
+            .record(&answer(&["1", "5"]), &Status::Pass, &input_hash)
+            .unwrap();
+        assert!(conflicts.is_empty());
+
+        let conflicts = history
+            .record(&answer(&["-1", "7"]), &Status::Unverified, &input_hash)
+            .unwrap();
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|conflict| (conflict.part, conflict.known_good.as_str()))
+                .collect_vec(),
+            [(1, "1"), (2, "5")]
+        );
+
+        history
+            .record(&answer(&["-1", "5"]), &Status::Pass, &input_hash)
+            .unwrap();
+
+        let conflicts = history
+            .record(&answer(&["1", "5"]), &Status::Unverified, &input_hash)
+            .unwrap();
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|conflict| (conflict.part, conflict.actual.as_str()))
+                .collect_vec(),
+            [(1, "1")]
+        );
+    }
+
+    #[test]
+    fn record_ignores_other_inputs() {
+        let mut history = History::disabled();
+
+        history
+            .record(&answer(&["1", "5"]), &Status::Pass, &InputHash::of("a"))
+            .unwrap();
+
+        let conflicts = history
+            .record(&answer(&["2", "6"]), &Status::Pass, &InputHash::of("b"))
+            .unwrap();
+
+        assert!(conflicts.is_empty());
+        assert_eq!(history.records(Some(2015), Some(1)).count(), 2);
+    }
+}
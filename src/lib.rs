@@ -1,28 +1,523 @@
-pub use crate::config::{Args, InputArgs};
-use crate::puzzle::Answer;
+//! Advent of Code solutions.
+//!
+//! Besides the command line driver, the crate exposes its solvers through the
+//! [`registry`] module:
+//!
+//! ```
+//! use advent_of_code::registry;
+//!
+//! assert!(registry::puzzles().any(|puzzle| puzzle == (2015, 1)));
+//!
+//! let answer = registry::solve(2015, 1, "(()))").unwrap();
+//! assert_eq!(answer.results, ["-1", "5"]);
+//! ```
 
+use crate::bench::{Baseline, BenchReport, Benchmark, Failure};
+use crate::catalog::Catalog;
+use crate::client::{Client, Fetch, Submission, Verdict};
+use crate::config::PuzzleFiles;
+pub use crate::config::{
+    Args, BenchArgs, ClientArgs, Command, ExamplesArgs, FetchArgs, HistoryArgs, InputArgs,
+    ListArgs, NewArgs, SubmitArgs,
+};
+pub use crate::error::Error;
+pub use crate::format::Format;
+use crate::history::{History, HistoryReport, InputHash};
+pub use crate::puzzle::{Answer, Example, Part, Session, Solver, Timings};
+use crate::registry::YEARS;
+use crate::report::{Entry, Report, Status};
+use crate::scaffold::Scaffold;
+use crate::watch::Watcher;
+use itertools::Itertools;
+use std::any::Any;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::Duration;
+
+mod bench;
+mod catalog;
+mod client;
 mod config;
-mod dictionary;
-mod puzzle;
+pub mod dictionary;
+mod error;
+mod format;
+#[cfg(test)]
+mod fuzz;
+mod history;
+mod pool;
+pub mod puzzle;
+pub mod registry;
+mod report;
+mod scaffold;
+mod settings;
+mod watch;
+
+pub mod year_2015;
+
+pub enum Output {
+    Answer(Answer),
+    Check(Entry),
+    Report(Report),
+    Catalog(Catalog),
+    Scaffold(Scaffold),
+    Bench(BenchReport),
+    History(HistoryReport),
+    Fetch(Fetch),
+    Submission(Submission),
+}
+
+impl Output {
+    pub fn is_success(&self) -> bool {
+        match self {
+            Self::Answer(answer) => !answer.is_timed_out(),
+            Self::Check(entry) => entry.is_success(),
+            Self::Report(report) => report.is_success(),
+            Self::Catalog(_) | Self::Scaffold(_) | Self::History(_) | Self::Fetch(_) => true,
+            Self::Bench(report) => report.is_success(),
+            Self::Submission(submission) => submission.verdict.is_success(),
+        }
+    }
+
+    /// Exits with the timeout code when running out of time is the only failure.
+    pub fn exit_code(&self) -> u8 {
+        let is_timed_out = match self {
+            Self::Answer(answer) => answer.is_timed_out(),
+            Self::Check(entry) => entry.is_timed_out(),
+            Self::Report(report) => report.is_timed_out(),
+            _ => false,
+        };
+
+        if self.is_success() {
+            0
+        } else if is_timed_out {
+            Error::TimedOut.exit_code()
+        } else {
+            1
+        }
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Answer(answer) => answer.fmt(f),
+            Self::Check(entry) => {
+                if let Some(answer) = &entry.answer {
+                    answer.fmt(f)?;
+                }
+
+                writeln!(f, "{entry}")
+            }
+            Self::Report(report) => report.fmt(f),
+            Self::Catalog(catalog) => catalog.fmt(f),
+            Self::Scaffold(scaffold) => scaffold.fmt(f),
+            Self::Bench(report) => report.fmt(f),
+            Self::History(report) => report.fmt(f),
+            Self::Fetch(fetch) => fetch.fmt(f),
+            Self::Submission(submission) => submission.fmt(f),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct RunOptions {
+    part: Option<usize>,
+    timeout: Option<Duration>,
+    trace: bool,
+}
+
+impl RunOptions {
+    fn for_day(self, year: u16, day: u16) -> anyhow::Result<Self> {
+        let timeout = match self.timeout {
+            Some(timeout) => Some(timeout),
+            None => settings::settings().timeout(year, day)?,
+        };
+
+        Ok(Self { timeout, ..self })
+    }
+}
+
+pub fn process<F>(args: Args, mut warn: F) -> anyhow::Result<Output>
+where
+    F: FnMut(String),
+{
+    let jobs = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+
+    if let Some(command) = args.command {
+        return match command {
+            Command::List(list) => Ok(Output::Catalog(list_puzzles(list)?)),
+            Command::New(new) => Ok(Output::Scaffold(Scaffold::generate(
+                new.year,
+                new.day,
+                new.title.as_deref(),
+            )?)),
+            Command::Bench(bench) => Ok(Output::Bench(bench_days(bench)?)),
+            Command::Examples(examples) => Ok(Output::Report(run_examples(examples, jobs))),
+            Command::History(history) => Ok(Output::History(HistoryReport {
+                history: History::load(&history::default_history_path())?,
+                year: history.year,
+                day: history.day,
+            })),
+            Command::Fetch(fetch) => Ok(Output::Fetch(Fetch::run(
+                &Client::new(fetch.client.resolve()),
+                fetch.year,
+                fetch.day,
+                fetch.force,
+            )?)),
+            Command::Submit(submit) => Ok(Output::Submission(submit_answer(submit, &mut warn)?)),
+        };
+    }
+
+    let options = RunOptions {
+        part: args.part.map(usize::from),
+        timeout: args.timeout,
+        trace: args.trace,
+    };
+
+    let mut history = if args.no_history {
+        History::disabled()
+    } else {
+        History::load(&history::default_history_path())?
+    };
+
+    let output = match (args.year, args.day) {
+        (Some(year), Some(day)) if args.check => {
+            let (entry, input_hash) = check_day(year, day, args.input, options)?;
+            record_entry(&mut history, &entry, &input_hash, &mut warn)?;
+            Output::Check(entry)
+        }
+        (Some(year), Some(day)) => {
+            let (answer, input_hash) = solve_day(year, day, args.input, options)?;
+            record_answer(
+                &mut history,
+                &answer,
+                &Status::Unverified,
+                &input_hash,
+                &mut warn,
+            )?;
+            Output::Answer(answer)
+        }
+        (Some(year), None) => {
+            Output::Report(solve_all(&[year], options, jobs, &mut history, &mut warn)?)
+        }
+        (None, None) => Output::Report(solve_all(YEARS, options, jobs, &mut history, &mut warn)?),
+        (None, Some(_)) => anyhow::bail!("day given without a year"),
+    };
+
+    Ok(output)
+}
+
+pub fn watch<F>(args: Args, emit: F) -> anyhow::Result<Infallible>
+where
+    F: FnMut(String),
+{
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        anyhow::bail!("watching requires a year and a day");
+    };
+
+    let options = RunOptions {
+        part: args.part.map(usize::from),
+        timeout: args.timeout,
+        trace: args.trace,
+    };
+
+    let path = args.input.watch_path(year, day)?;
+
+    Watcher::new(year, day, options.for_day(year, day)?, args.timings).run(&path, emit)
+}
+
+fn list_puzzles(list: ListArgs) -> anyhow::Result<Catalog> {
+    let catalog = match list.year {
+        Some(year) => Catalog::new(&[year]),
+        None => Catalog::new(YEARS),
+    };
+
+    if let Some(readme) = list.readme {
+        catalog.update_readme(&readme)?;
+    }
+
+    Ok(catalog)
+}
+
+fn bench_days(bench: BenchArgs) -> anyhow::Result<BenchReport> {
+    let baseline_path = bench.baseline.unwrap_or_else(bench::default_baseline_path);
+    let mut baseline = Baseline::load(&baseline_path)?;
+
+    let days = match bench.day {
+        Some(day) => vec![day],
+        None => registry::days(bench.year).to_vec(),
+    };
+
+    let mut report = BenchReport {
+        benchmarks: Vec::new(),
+        failures: Vec::new(),
+        threshold: bench.threshold,
+    };
+
+    for day in days {
+        match Benchmark::run(bench.year, day, bench.runs as usize, &baseline) {
+            Ok(benchmark) => report.benchmarks.push(benchmark),
+            Err(error) if bench.day.is_some() => return Err(error),
+            Err(error) => report.failures.push(Failure {
+                year: bench.year,
+                day,
+                error,
+            }),
+        }
+    }
+
+    if bench.save {
+        baseline.record(&report);
+        baseline.save(&baseline_path)?;
+    }
+
+    Ok(report)
+}
+
+fn submit_answer<F>(submit: SubmitArgs, warn: &mut F) -> anyhow::Result<Submission>
+where
+    F: FnMut(String),
+{
+    let (year, day, part) = (submit.year, submit.day, usize::from(submit.part));
+
+    let answer = match submit.answer {
+        Some(answer) => answer,
+        None => {
+            let input = InputArgs {
+                input: None,
+                file_path: None,
+            };
+
+            let options = RunOptions {
+                part: Some(part),
+                ..RunOptions::default()
+            };
 
-mod year_2015;
+            let (answer, _) = solve_day(year, day, input, options)?;
 
-pub fn process(args: Args) -> anyhow::Result<Answer> {
-    let year = args.year;
-    let day = args.day;
+            let Some(result) = answer.results.into_iter().nth(part - 1) else {
+                anyhow::bail!("no result for part {part}");
+            };
 
-    let input = args.input.extract()?;
+            result
+        }
+    };
+
+    let verdict = Client::new(submit.client.resolve()).submit(year, day, part, &answer)?;
+
+    if matches!(verdict, Verdict::Accepted)
+        && !PuzzleFiles::new(year, day).record_solution(part, &answer)?
+    {
+        warn(format!(
+            "accepted answer for {year}/{day:0>2} part {part} was not recorded \
+             because part {} has no recorded solution",
+            part - 1
+        ));
+    }
+
+    Ok(Submission {
+        year,
+        day,
+        part,
+        answer,
+        verdict,
+    })
+}
+
+fn solve_day(
+    year: u16,
+    day: u16,
+    input: InputArgs,
+    options: RunOptions,
+) -> anyhow::Result<(Answer, InputHash)> {
+    let input = input.extract(year, day)?;
+
+    let Some(solver) = registry::solver(year, day) else {
+        return Err(Error::NoSolver { year, day }.into());
+    };
+
+    let answer = run_solver(solver.as_ref(), &input, options.for_day(year, day)?)?;
+
+    Ok((answer, InputHash::of(&input)))
+}
+
+fn check_day(
+    year: u16,
+    day: u16,
+    input: InputArgs,
+    options: RunOptions,
+) -> anyhow::Result<(Entry, InputHash)> {
+    let (answer, input_hash) = solve_day(year, day, input, options)?;
+
+    let Some(expected_solution) = PuzzleFiles::new(year, day).read_solution()? else {
+        anyhow::bail!("no recorded solution for: year {year}, day {day}");
+    };
+
+    Ok((Entry::verify(answer, Some(expected_solution)), input_hash))
+}
+
+fn solve_all<F>(
+    years: &[u16],
+    options: RunOptions,
+    jobs: usize,
+    history: &mut History,
+    warn: &mut F,
+) -> anyhow::Result<Report>
+where
+    F: FnMut(String),
+{
+    let puzzles = years
+        .iter()
+        .flat_map(|&year| registry::days(year).iter().map(move |&day| (year, day)))
+        .collect_vec();
+
+    let runs = pool::map(&puzzles, jobs, |&(year, day)| {
+        verify_day(year, day, options)
+    });
+
+    let mut report = Report::default();
+
+    for (&(year, day), run) in puzzles.iter().zip(runs) {
+        let (entry, input_hash) = match run.unwrap_or_else(|payload| Err(panic_error(payload))) {
+            Ok((entry, input_hash)) => (entry, Some(input_hash)),
+            Err(error) => (Entry::error(year, day, error), None),
+        };
+
+        if let Some(input_hash) = input_hash {
+            record_entry(history, &entry, &input_hash, warn)?;
+        }
+
+        report.add(entry);
+    }
+
+    Ok(report)
+}
+
+fn verify_day(year: u16, day: u16, options: RunOptions) -> anyhow::Result<(Entry, InputHash)> {
+    let files = PuzzleFiles::new(year, day);
 
-    let solver = match year {
-        2015 => year_2015::select_solver(day),
-        _ => None,
+    let Some(solver) = registry::solver(year, day) else {
+        return Err(Error::NoSolver { year, day }.into());
     };
 
-    let Some(solver) = solver else {
-        anyhow::bail!("no solver found for: year {year}, day {day}");
+    let input = files.read_input()?;
+    let answer = run_guarded(solver.as_ref(), &input, options.for_day(year, day)?)?;
+    let expected_solution = files.read_solution()?;
+
+    Ok((
+        Entry::verify(answer, expected_solution),
+        InputHash::of(&input),
+    ))
+}
+
+fn record_entry<F>(
+    history: &mut History,
+    entry: &Entry,
+    input_hash: &InputHash,
+    warn: &mut F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(String),
+{
+    match &entry.answer {
+        Some(answer) => record_answer(history, answer, &entry.status, input_hash, warn),
+        None => Ok(()),
+    }
+}
+
+fn record_answer<F>(
+    history: &mut History,
+    answer: &Answer,
+    status: &Status,
+    input_hash: &InputHash,
+    warn: &mut F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(String),
+{
+    for conflict in history.record(answer, status, input_hash)? {
+        warn(conflict.to_string());
+    }
+
+    Ok(())
+}
+
+fn run_examples(examples: ExamplesArgs, jobs: usize) -> Report {
+    let years = examples
+        .year
+        .map_or_else(|| YEARS.to_vec(), |year| vec![year]);
+
+    let examples = years
+        .iter()
+        .flat_map(|&year| registry::days(year).iter().map(move |&day| (year, day)))
+        .filter(|&(_, day)| examples.day.is_none_or(|selected| selected == day))
+        .filter_map(|(year, day)| registry::metadata(year, day))
+        .flat_map(|metadata| {
+            metadata
+                .examples
+                .iter()
+                .enumerate()
+                .map(move |example| (metadata, example))
+        })
+        .collect_vec();
+
+    let entries = pool::map(&examples, jobs, |&(metadata, (_, example))| {
+        verify_example(metadata.year, metadata.day, example)
+    });
+
+    let mut report = Report::default();
+
+    for (&(metadata, (idx, _)), entry) in examples.iter().zip(entries) {
+        let (year, day) = (metadata.year, metadata.day);
+
+        let entry = match entry.unwrap_or_else(|payload| Err(panic_error(payload))) {
+            Ok(entry) => entry,
+            Err(error) => Entry::error(year, day, error),
+        };
+
+        report.add(entry.for_example(idx + 1));
+    }
+
+    report
+}
+
+fn verify_example(year: u16, day: u16, example: &Example) -> anyhow::Result<Entry> {
+    let Some(solver) = registry::solver(year, day) else {
+        return Err(Error::NoSolver { year, day }.into());
     };
 
-    let answer = solver.solve(&input)?;
+    let answer = run_guarded(solver.as_ref(), example.input, RunOptions::default())?;
+
+    Ok(Entry::verify(answer, Some(example.answers.join("\n"))))
+}
+
+fn run_guarded(solver: &dyn Solver, input: &str, options: RunOptions) -> anyhow::Result<Answer> {
+    panic::catch_unwind(AssertUnwindSafe(|| run_solver(solver, input, options)))
+        .map_err(panic_error)?
+}
+
+fn panic_error(payload: Box<dyn Any + Send>) -> anyhow::Error {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+
+    match message {
+        Some(message) => anyhow::anyhow!("solver panicked: {message}"),
+        None => anyhow::anyhow!("solver panicked"),
+    }
+}
+
+fn run_solver(solver: &dyn Solver, input: &str, options: RunOptions) -> anyhow::Result<Answer> {
+    let session = Session::start(options.part)
+        .with_timeout(options.timeout)
+        .with_trace(options.trace);
 
-    Ok(answer)
+    solver.solve_in(input, session)
 }
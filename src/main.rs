@@ -1,7 +1,47 @@
-use advent_of_code::{Args, process};
+use advent_of_code::{Args, Error, process, watch};
 use clap::Parser;
+use std::process::ExitCode;
 
-fn main() -> anyhow::Result<()> {
-    let answer = process(Args::parse())?;
-    Ok(println!("{}", answer))
+fn main() -> ExitCode {
+    let args = match Args::parse().with_config() {
+        Ok(args) => args,
+        Err(error) => return fail(error),
+    };
+
+    let timings = args.timings;
+    let format = args.format.unwrap_or_default();
+    let selection = args.command.is_none().then_some((args.year, args.day));
+
+    if args.watch {
+        let Err(error) = watch(args, |text| println!("{text}"));
+        return fail(error);
+    }
+
+    let output = match process(args, |warning| eprintln!("Warning: {warning}")) {
+        Ok(output) => output,
+        Err(error) => {
+            if let Some((year, day)) = selection
+                && let Some(rendered) = format.render_error(year, day, &error)
+            {
+                println!("{rendered}");
+                return ExitCode::from(exit_code(&error));
+            }
+
+            return fail(error);
+        }
+    };
+
+    println!("{}", format.render(&output, timings));
+
+    ExitCode::from(output.exit_code())
+}
+
+fn fail(error: anyhow::Error) -> ExitCode {
+    eprintln!("Error: {error:?}");
+
+    ExitCode::from(exit_code(&error))
+}
+
+fn exit_code(error: &anyhow::Error) -> u8 {
+    error.downcast_ref::<Error>().map_or(1, Error::exit_code)
 }
@@ -0,0 +1,109 @@
+use itertools::Itertools;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Applies `f` to every item on up to `workers` threads and returns the results in
+/// item order. A panic in `f` is caught and returned in that item's slot.
+pub fn map<T, R, F>(items: &[T], workers: usize, f: F) -> Vec<thread::Result<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let workers = workers.clamp(1, items.len().max(1));
+
+    let results = thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(idx) else {
+                            break;
+                        };
+
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)));
+                        results.push((idx, result));
+                    }
+
+                    results
+                })
+            })
+            .collect_vec();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect_vec()
+    });
+
+    results
+        .into_iter()
+        .sorted_by_key(|(idx, _)| *idx)
+        .map(|(_, result)| result)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 4)]
+    #[case(4, 16)]
+    #[case(32, 8)]
+    fn map_preserves_order(#[case] workers: usize, #[case] len: usize) {
+        let items = (0..len as u64).collect_vec();
+
+        // Earlier items take longest, so workers finish them out of order.
+        let results = map(&items, workers, |&item| {
+            thread::sleep(Duration::from_millis(len as u64 - item));
+            item * 2
+        });
+
+        let results = results.into_iter().map(Result::unwrap).collect_vec();
+
+        assert_eq!(results, items.iter().map(|item| item * 2).collect_vec());
+    }
+
+    #[test]
+    fn map_returns_errors_and_panics_in_their_slot() {
+        let items = (0..12).collect_vec();
+
+        let results = map(&items, 3, |&item| match item % 3 {
+            0 => Ok(item),
+            1 => Err(format!("error {item}")),
+            _ => panic!("panic {item}"),
+        });
+
+        assert_eq!(results.len(), items.len());
+
+        for (item, result) in items.iter().zip(results) {
+            match (item % 3, result) {
+                (0, Ok(Ok(value))) => assert_eq!(value, *item),
+                (1, Ok(Err(error))) => assert_eq!(error, format!("error {item}")),
+                (2, Err(payload)) => {
+                    assert_eq!(
+                        payload.downcast_ref::<String>(),
+                        Some(&format!("panic {item}"))
+                    );
+                }
+                (_, result) => panic!("unexpected result for item {item}: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn map_handles_empty_input() {
+        let results = map(&[] as &[u32], 4, |item| *item);
+
+        assert!(results.is_empty());
+    }
+}
@@ -1,13 +1,402 @@
+use crate::error::Error;
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
-pub trait Solver {
-    fn solve(&self, input: &str) -> anyhow::Result<Answer>;
+/// A solution to a single puzzle day.
+pub trait Solver: Send + Sync {
+    fn metadata(&self) -> &'static Metadata;
+
+    /// Solves the puzzle, recording progress in `session`; prefer [`Solver::solve`].
+    fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer>;
+
+    /// Solves every part of the puzzle for `input`.
+    fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        self.solve_in(input, Session::start(None))
+    }
+
+    /// Solves only `part` (1-based), reporting the other parts as skipped.
+    fn solve_part(&self, input: &str, part: usize) -> anyhow::Result<Answer> {
+        self.solve_in(input, Session::start(Some(part)))
+    }
+
+    /// Solves the puzzle within a prepared session, e.g. one with a timeout.
+    fn solve_in(&self, input: &str, session: Session) -> anyhow::Result<Answer> {
+        session.solve(self, input)
+    }
+}
+
+pub enum Part<T> {
+    Solved(T),
+    Skipped,
+    TimedOut,
+}
+
+impl<T> Part<T> {
+    pub fn solved(&self) -> Option<&T> {
+        match self {
+            Self::Solved(value) => Some(value),
+            Self::Skipped | Self::TimedOut => None,
+        }
+    }
+}
+
+impl<T: Display> Display for Part<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Solved(value) => value.fmt(f),
+            Self::Skipped => write!(f, "skipped"),
+            Self::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// A cooperative cancellation signal that long-running solvers poll.
+#[derive(Clone)]
+pub struct Cancellation {
+    state: Arc<CancellationState>,
+}
+
+struct CancellationState {
+    started: Instant,
+    deadline_nanos: AtomicU64,
+    cancelled: AtomicBool,
+}
+
+impl Cancellation {
+    fn new(started: Instant) -> Self {
+        let state = CancellationState {
+            started,
+            deadline_nanos: AtomicU64::new(u64::MAX),
+            cancelled: AtomicBool::new(false),
+        };
+
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        let deadline_nanos = self.state.deadline_nanos.load(Ordering::Relaxed);
+        let elapsed_nanos = self.state.started.elapsed().as_nanos();
+
+        self.state.cancelled.load(Ordering::Relaxed) || elapsed_nanos >= u128::from(deadline_nanos)
+    }
+
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_cancelled() {
+            return Err(Error::TimedOut);
+        }
+
+        Ok(())
+    }
+
+    fn set_deadline(&self, deadline: Option<Instant>) {
+        let deadline_nanos = deadline.map_or(u64::MAX, |deadline| {
+            let nanos = deadline.duration_since(self.state.started).as_nanos();
+            u64::try_from(nanos).unwrap_or(u64::MAX)
+        });
+
+        self.state
+            .deadline_nanos
+            .store(deadline_nanos, Ordering::Relaxed);
+    }
+}
+
+pub struct Session {
+    selected_part: Option<usize>,
+    started: Instant,
+    last_lap: Instant,
+    timings: Timings,
+    timeout: Option<Duration>,
+    cancellation: Cancellation,
+    trace: Option<Vec<String>>,
+    timed_out: Vec<usize>,
+}
+
+impl Session {
+    pub fn start(selected_part: Option<usize>) -> Self {
+        let now = Instant::now();
+
+        Self {
+            selected_part,
+            started: now,
+            last_lap: now,
+            timings: Timings::default(),
+            timeout: None,
+            cancellation: Cancellation::new(now),
+            trace: None,
+            timed_out: Vec::new(),
+        }
+    }
+
+    /// Limits every stage (parsing and each part) to `timeout`.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self.arm_deadline();
+
+        self
+    }
+
+    /// Collects trace lines that solvers emit to explain how they got their results.
+    pub fn with_trace(mut self, enabled: bool) -> Self {
+        self.trace = enabled.then(Vec::new);
+
+        self
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.trace.is_some()
+    }
+
+    pub fn trace(&mut self, line: impl Display) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(line.to_string());
+        }
+    }
+
+    pub fn cancellation(&self) -> Cancellation {
+        self.cancellation.clone()
+    }
+
+    pub fn parsed(&mut self) {
+        self.timings.parse = Some(self.lap());
+    }
+
+    pub fn part_solved(&mut self) {
+        let elapsed = self.lap();
+        self.timings.parts.push(Some(elapsed));
+    }
+
+    pub fn solve_part<T, F>(&mut self, solve: F) -> anyhow::Result<Part<T>>
+    where
+        F: FnOnce() -> anyhow::Result<T>,
+    {
+        let part = self.timings.parts.len() + 1;
+
+        if !is_selected(self.selected_part, part) {
+            self.lap();
+            self.timings.parts.push(None);
+
+            return Ok(Part::Skipped);
+        }
+
+        let value = match solve() {
+            Ok(value) => Part::Solved(value),
+            Err(error) if matches!(error.downcast_ref(), Some(Error::TimedOut)) => {
+                self.timed_out.push(part);
+                Part::TimedOut
+            }
+            Err(error) => return Err(error),
+        };
+
+        self.part_solved();
+
+        Ok(value)
+    }
+
+    /// Solves parts that one pass computes together, crediting each selected
+    /// part with the time of the whole pass.
+    pub fn solve_parts<T, F, const N: usize>(&mut self, solve: F) -> anyhow::Result<[Part<T>; N]>
+    where
+        F: FnOnce() -> anyhow::Result<[T; N]>,
+    {
+        let parts: [usize; N] = std::array::from_fn(|idx| self.timings.parts.len() + idx + 1);
+        let selected = parts.map(|part| is_selected(self.selected_part, part));
+
+        if !selected.contains(&true) {
+            self.lap();
+            self.timings.parts.extend([None; N]);
+
+            return Ok(parts.map(|_| Part::Skipped));
+        }
+
+        let values = match solve() {
+            Ok(values) => Some(values),
+            Err(error) if matches!(error.downcast_ref(), Some(Error::TimedOut)) => None,
+            Err(error) => return Err(error),
+        };
+
+        let elapsed = self.lap();
+        let mut values = values.map(|values| values.map(Some));
+
+        Ok(std::array::from_fn(|idx| {
+            let value = values.as_mut().and_then(|values| values[idx].take());
+
+            if !selected[idx] {
+                self.timings.parts.push(None);
+                return Part::Skipped;
+            }
+
+            self.timings.parts.push(Some(elapsed));
+
+            match value {
+                Some(value) => Part::Solved(value),
+                None => {
+                    self.timed_out.push(parts[idx]);
+                    Part::TimedOut
+                }
+            }
+        }))
+    }
+
+    fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.last_lap;
+        self.last_lap = now;
+        self.arm_deadline();
+
+        elapsed
+    }
+
+    fn arm_deadline(&self) {
+        let deadline = self.timeout.map(|timeout| self.last_lap + timeout);
+        self.cancellation.set_deadline(deadline);
+    }
+
+    fn solve<S: Solver + ?Sized>(mut self, solver: &S, input: &str) -> anyhow::Result<Answer> {
+        let input = solver.metadata().input.prepare(input);
+
+        if input.trim().is_empty() {
+            return Err(Error::parse(1, "empty input").into());
+        }
+
+        let answer = solver.run(&input, &mut self)?;
+
+        self.timings.total = self.started.elapsed();
+
+        Ok(Answer {
+            selected_part: self.selected_part,
+            timings: self.timings,
+            trace: self.trace.unwrap_or_default(),
+            timed_out: self.timed_out,
+            ..answer
+        })
+    }
 }
 
+fn is_selected(selected_part: Option<usize>, part: usize) -> bool {
+    selected_part.is_none_or(|selected_part| selected_part == part)
+}
+
+#[derive(Default, Debug)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub parts: Vec<Option<Duration>>,
+    pub total: Duration,
+}
+
+/// Descriptive information about a puzzle, declared alongside its solver.
+#[derive(Debug)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u16,
+    pub title: &'static str,
+    pub parts: &'static [&'static str],
+    pub completion: Completion,
+    pub input: InputMode,
+    pub examples: &'static [Example],
+}
+
+/// A sample input with the answers the puzzle description gives for it.
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    pub answers: &'static [&'static str],
+}
+
+impl Metadata {
+    pub fn is_complete(&self) -> bool {
+        self.completion == Completion::Complete
+    }
+}
+
+/// How much of a puzzle the solver answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Completion {
+    Complete,
+    Partial,
+    Pending,
+}
+
+impl Completion {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Complete => "complete",
+            Self::Partial => "partial",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+/// How the input text is prepared before a solver sees it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputMode {
+    /// Passed through untouched.
+    Raw,
+    /// Without a byte order mark, with `\n` line endings and no trailing newline.
+    Normalized,
+}
+
+impl InputMode {
+    pub fn prepare(self, input: &str) -> Cow<'_, str> {
+        match self {
+            Self::Raw => Cow::Borrowed(input),
+            Self::Normalized => normalize(input),
+        }
+    }
+}
+
+fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    if input.contains('\r') {
+        let input = input.replace("\r\n", "\n");
+        let input = input.strip_suffix('\n').unwrap_or(&input);
+
+        Cow::Owned(input.to_string())
+    } else {
+        Cow::Borrowed(input.strip_suffix('\n').unwrap_or(input))
+    }
+}
+
+/// Results of a solved puzzle, one entry per part.
+#[derive(Debug)]
 pub struct Answer {
     pub year: u16,
     pub day: u16,
     pub results: Vec<String>,
+    pub selected_part: Option<usize>,
+    pub timings: Timings,
+    pub trace: Vec<String>,
+    pub timed_out: Vec<usize>,
+}
+
+impl Answer {
+    pub fn is_selected(&self, part: usize) -> bool {
+        is_selected(self.selected_part, part)
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        !self.timed_out.is_empty()
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = (usize, &str, Option<Duration>)> {
+        self.results
+            .iter()
+            .enumerate()
+            .map(|(idx, result)| {
+                let elapsed = self.timings.parts.get(idx).copied().flatten();
+                (idx + 1, result.as_str(), elapsed)
+            })
+            .filter(|(part, _, _)| self.is_selected(*part))
+    }
 }
 
 impl Display for Answer {
@@ -19,8 +408,30 @@ impl Display for Answer {
             day = self.day
         )?;
 
-        for (i, result) in self.results.iter().enumerate() {
-            writeln!(f, "Part {index}: {result}", index = i + 1)?;
+        for (part, result, elapsed) in self.parts() {
+            write!(f, "Part {part}: {result}")?;
+
+            if let Some(elapsed) = elapsed.filter(|_| f.alternate()) {
+                write!(f, " ({elapsed:.2?})")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        if !self.trace.is_empty() {
+            writeln!(f, "Trace:")?;
+
+            for line in &self.trace {
+                writeln!(f, "  {line}")?;
+            }
+        }
+
+        if f.alternate() {
+            if let Some(elapsed) = self.timings.parse {
+                writeln!(f, "Parse: {elapsed:.2?}")?;
+            }
+
+            writeln!(f, "Total: {total:.2?}", total = self.timings.total)?;
         }
 
         Ok(())
@@ -28,7 +439,9 @@ impl Display for Answer {
 }
 
 macro_rules! puzzle_solver_selector {
-    ($($idx:literal = $puzzle:expr),*$(,)?) => (
+    ($($idx:literal = $puzzle:path),*$(,)?) => (
+        pub const DAYS: &[u16] = &[$($idx),+];
+
         pub fn select_solver(day: u16) -> Option<Box<dyn Solver>> {
             let solver: Box<dyn Solver> = match day {
                 $($idx => Box::new($puzzle),)+
@@ -36,25 +449,66 @@ macro_rules! puzzle_solver_selector {
             };
             Some(solver)
         }
+
+        pub fn select_metadata(day: u16) -> Option<&'static $crate::puzzle::Metadata> {
+            match day {
+                $($idx => Some(&<$puzzle>::METADATA),)+
+                _ => None,
+            }
+        }
     )
 }
 
 pub(crate) use puzzle_solver_selector;
 
 macro_rules! puzzle_solver {
-    ([$year:literal, $day:literal] = $body:tt) => (
+    (
+        [$year:literal, $day:literal] = {
+            title: $title:literal,
+            parts: [$($label:literal),+ $(,)?],
+            $(completion: $completion:ident,)?
+            $(input: $input:ident,)?
+            $(examples: [$(($example:expr, [$($expected:literal),* $(,)?])),* $(,)?],)?
+
+            fn $($body:tt)*
+        }
+    ) => (
         pub struct Puzzle;
 
         type Answer = $crate::puzzle::Answer;
+        type Session = $crate::puzzle::Session;
 
-        impl $crate::puzzle::Solver for Puzzle $body
+        impl $crate::puzzle::Solver for Puzzle {
+            fn metadata(&self) -> &'static $crate::puzzle::Metadata {
+                &Self::METADATA
+            }
+
+            fn $($body)*
+        }
 
         impl Puzzle {
+            pub const METADATA: $crate::puzzle::Metadata = $crate::puzzle::Metadata {
+                year: $year,
+                day: $day,
+                title: $title,
+                parts: &[$($label),+],
+                completion: $crate::puzzle::puzzle_completion!($($completion)?),
+                input: $crate::puzzle::puzzle_input_mode!($($input)?),
+                examples: &[$($($crate::puzzle::Example {
+                    input: $example,
+                    answers: &[$($expected),*],
+                }),*)?],
+            };
+
             fn answer(results: Vec<String>) -> Answer {
                 Answer {
                     year: $year,
                     day: $day,
                     results,
+                    selected_part: None,
+                    timings: Default::default(),
+                    trace: Vec::new(),
+                    timed_out: Vec::new(),
                 }
             }
         }
@@ -63,11 +517,38 @@ macro_rules! puzzle_solver {
         mod file_tests {
             $crate::puzzle::puzzle_test_file_input!($year, $day);
         }
+
+        #[cfg(test)]
+        mod example_tests {
+            $crate::puzzle::puzzle_test_examples!($($(($example, [$($expected),*])),*)?);
+        }
     )
 }
 
 pub(crate) use puzzle_solver;
 
+macro_rules! puzzle_completion {
+    () => {
+        $crate::puzzle::Completion::Complete
+    };
+    ($completion:ident) => {
+        $crate::puzzle::Completion::$completion
+    };
+}
+
+pub(crate) use puzzle_completion;
+
+macro_rules! puzzle_input_mode {
+    () => {
+        $crate::puzzle::InputMode::Normalized
+    };
+    ($input:ident) => {
+        $crate::puzzle::InputMode::$input
+    };
+}
+
+pub(crate) use puzzle_input_mode;
+
 macro_rules! answer {
     ($($result:expr),*) => (
         return Ok(Puzzle::answer(vec![
@@ -126,3 +607,118 @@ macro_rules! puzzle_test_file_input {
 
 #[cfg(test)]
 pub(crate) use puzzle_test_file_input;
+
+#[cfg(test)]
+macro_rules! puzzle_test_examples {
+    () => {};
+    ($(($example:expr, [$($expected:literal),*])),+) => {
+        use super::*;
+        use rstest::rstest;
+        use $crate::puzzle::Solver;
+
+        #[rstest]
+        $(#[case($example, &[$($expected),*])])+
+        fn examples(#[case] input: &str, #[case] expected_answers: &[&str]) {
+            let answer = Puzzle.solve(input).unwrap();
+
+            for (idx, expected) in expected_answers.iter().enumerate() {
+                assert_eq!(answer.results[idx], *expected, "part {}", idx + 1);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use puzzle_test_examples;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(None, ["1", "2"], [true, true])]
+    #[case(Some(1), ["1", "skipped"], [true, false])]
+    #[case(Some(2), ["skipped", "2"], [false, true])]
+    fn solve_parts_tests(
+        #[case] selected_part: Option<usize>,
+        #[case] expected: [&str; 2],
+        #[case] expected_timed: [bool; 2],
+    ) {
+        let mut session = Session::start(selected_part);
+
+        let parts = session.solve_parts(|| Ok([1, 2])).unwrap();
+
+        assert_eq!(parts.map(|part| part.to_string()), expected);
+        assert_eq!(
+            session
+                .timings
+                .parts
+                .iter()
+                .map(Option::is_some)
+                .collect::<Vec<_>>(),
+            expected_timed
+        );
+    }
+
+    #[test]
+    fn solve_parts_skips_unselected_pass() {
+        let mut session = Session::start(Some(3));
+
+        let parts = session
+            .solve_parts::<u32, _, 2>(|| panic!("pass should be skipped"))
+            .unwrap();
+
+        assert_eq!(parts.map(|part| part.to_string()), ["skipped", "skipped"]);
+        assert_eq!(session.timings.parts.len(), 2);
+    }
+
+    #[test]
+    fn solve_parts_times_out_selected_parts() {
+        let mut session = Session::start(Some(2));
+
+        let parts = session
+            .solve_parts::<u32, _, 2>(|| Err(Error::TimedOut.into()))
+            .unwrap();
+
+        assert_eq!(parts.map(|part| part.to_string()), ["skipped", "timed out"]);
+        assert_eq!(session.timed_out, [2]);
+    }
+
+    #[rstest]
+    #[case("1\n2", "1\n2")]
+    #[case("1\n2\n", "1\n2")]
+    #[case("1\n2\n\n", "1\n2\n")]
+    #[case("\u{feff}1\n2\n", "1\n2")]
+    #[case("1\r\n2\r\n", "1\n2")]
+    #[case("1\r\n2\r\n\r\n", "1\n2\n")]
+    #[case("\u{feff}1\r\n2", "1\n2")]
+    #[case("1\u{feff}\n", "1\u{feff}")]
+    #[case("\n", "")]
+    #[case("", "")]
+    fn normalize_tests(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(InputMode::Normalized.prepare(input), expected);
+    }
+
+    #[rstest]
+    #[case("1\n2")]
+    #[case("\u{feff}1\n2")]
+    #[case("1\n")]
+    fn normalize_borrows_without_carriage_returns(#[case] input: &str) {
+        assert!(matches!(
+            InputMode::Normalized.prepare(input),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[rstest]
+    #[case("\u{feff}1\r\n2\r\n\n")]
+    #[case("1\n2\n")]
+    #[case("")]
+    fn raw_passes_input_through(#[case] input: &str) {
+        let prepared = InputMode::Raw.prepare(input);
+
+        assert!(matches!(prepared, Cow::Borrowed(_)));
+        assert_eq!(prepared, input);
+    }
+}
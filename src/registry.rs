@@ -0,0 +1,89 @@
+//! Lookup of the solvers built into the crate, independent of the command line.
+
+use crate::error::Error;
+use crate::puzzle::{Answer, Metadata, Solver};
+use crate::year_2015;
+
+macro_rules! puzzle_registry {
+    ($($year:literal = $module:ident),+ $(,)?) => (
+        /// Years that have at least one solver.
+        pub const YEARS: &[u16] = &[$($year),+];
+
+        /// Days of `year` that have a solver, in ascending order.
+        pub fn days(year: u16) -> &'static [u16] {
+            match year {
+                $($year => $module::DAYS,)+
+                _ => &[],
+            }
+        }
+
+        /// The solver for the given puzzle, if one exists.
+        pub fn solver(year: u16, day: u16) -> Option<Box<dyn Solver>> {
+            match year {
+                $($year => $module::select_solver(day),)+
+                _ => None,
+            }
+        }
+
+        /// The metadata declared for the given puzzle, if it exists.
+        pub fn metadata(year: u16, day: u16) -> Option<&'static Metadata> {
+            match year {
+                $($year => $module::select_metadata(day),)+
+                _ => None,
+            }
+        }
+    )
+}
+
+puzzle_registry!(2015 = year_2015);
+
+/// Every available `(year, day)` pair, ordered by year and then by day.
+pub fn puzzles() -> impl Iterator<Item = (u16, u16)> {
+    YEARS
+        .iter()
+        .flat_map(|&year| days(year).iter().map(move |&day| (year, day)))
+}
+
+/// Solves both parts of the given puzzle for `input`.
+///
+/// Fails with [`Error::NoSolver`] when the puzzle is not available.
+pub fn solve(year: u16, day: u16, input: &str) -> anyhow::Result<Answer> {
+    let Some(solver) = solver(year, day) else {
+        return Err(Error::NoSolver { year, day }.into());
+    };
+
+    solver.solve(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unselected_parts_are_not_computed() {
+        for (year, day) in puzzles() {
+            let (Some(solver), Some(metadata)) = (solver(year, day), metadata(year, day)) else {
+                continue;
+            };
+
+            let Some(example) = metadata.examples.first() else {
+                continue;
+            };
+
+            for part in 1..=metadata.parts.len() {
+                let answer = solver.solve_part(example.input, part).unwrap();
+
+                for (idx, result) in answer.results.iter().enumerate() {
+                    let timing = answer.timings.parts.get(idx).copied().flatten();
+
+                    if idx + 1 == part {
+                        assert_eq!(result, example.answers[idx], "{year}/{day} part {part}");
+                    } else {
+                        assert_eq!(result, "skipped", "{year}/{day} part {}", idx + 1);
+                        assert!(timing.is_none(), "{year}/{day} timed part {}", idx + 1);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use crate::puzzle::Answer;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+pub struct Mismatch {
+    pub part: usize,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl Mismatch {
+    pub fn compare(answer: &Answer, expected_solution: &str) -> Vec<Self> {
+        expected_solution
+            .lines()
+            .enumerate()
+            .filter(|(idx, _)| {
+                answer.is_selected(idx + 1) && !answer.timed_out.contains(&(idx + 1))
+            })
+            .filter_map(|(idx, expected)| {
+                let actual = answer.results.get(idx);
+
+                match actual {
+                    Some(actual) if actual == expected => None,
+                    actual => Some(Self {
+                        part: idx + 1,
+                        expected: expected.to_string(),
+                        actual: actual.cloned(),
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.actual {
+            Some(actual) => write!(
+                f,
+                "part {part}: expected {expected}, got {actual}",
+                part = self.part,
+                expected = self.expected
+            ),
+            None => write!(
+                f,
+                "part {part}: expected {expected}, got no result",
+                part = self.part,
+                expected = self.expected
+            ),
+        }
+    }
+}
+
+pub enum Status {
+    Pass,
+    Fail(Vec<Mismatch>),
+    Unverified,
+    TimedOut(Vec<usize>),
+    Error(anyhow::Error),
+}
+
+impl Status {
+    pub const ERROR: &str = "error";
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail(_) => "fail",
+            Self::Unverified => "unverified",
+            Self::TimedOut(_) => "timeout",
+            Self::Error(_) => Self::ERROR,
+        }
+    }
+}
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u16,
+    pub example: Option<usize>,
+    pub status: Status,
+    pub answer: Option<Answer>,
+}
+
+impl Entry {
+    pub fn verify(answer: Answer, expected_solution: Option<String>) -> Self {
+        let mismatches = expected_solution
+            .as_deref()
+            .map(|expected_solution| Mismatch::compare(&answer, expected_solution));
+
+        let status = match mismatches {
+            Some(mismatches) if !mismatches.is_empty() => Status::Fail(mismatches),
+            _ if answer.is_timed_out() => Status::TimedOut(answer.timed_out.clone()),
+            Some(_) => Status::Pass,
+            None => Status::Unverified,
+        };
+
+        Self {
+            year: answer.year,
+            day: answer.day,
+            example: None,
+            status,
+            answer: Some(answer),
+        }
+    }
+
+    pub fn for_example(self, example: usize) -> Self {
+        Self {
+            example: Some(example),
+            ..self
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, Status::Pass | Status::Unverified)
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self.status, Status::TimedOut(_))
+    }
+
+    pub fn error(year: u16, day: u16, error: anyhow::Error) -> Self {
+        Self {
+            year,
+            day,
+            example: None,
+            status: Status::Error(error),
+            answer: None,
+        }
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{year}/{day:0>2}", year = self.year, day = self.day)?;
+
+        if let Some(example) = self.example {
+            write!(f, " example {example}")?;
+        }
+
+        write!(f, ": {}", self.status.label().to_uppercase())?;
+
+        match &self.status {
+            Status::Pass => (),
+            Status::Fail(mismatches) => write!(f, " ({})", mismatches.iter().join("; "))?,
+            Status::Unverified => {
+                if let Some(answer) = &self.answer {
+                    let results = answer.parts().map(|(_, result, _)| result).join(", ");
+                    write!(f, " ({results})")?;
+                }
+            }
+            Status::TimedOut(parts) => {
+                let parts = parts.iter().map(|part| format!("part {part}")).join(", ");
+                write!(f, " ({parts})")?;
+            }
+            Status::Error(error) => write!(f, " ({error:#})")?,
+        }
+
+        if let Some(answer) = self.answer.as_ref().filter(|_| f.alternate()) {
+            write!(f, " [{elapsed:.2?}]", elapsed = answer.timings.total)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    pub timed_out: usize,
+    pub unverified: usize,
+}
+
+#[derive(Default)]
+pub struct Report {
+    entries: Vec<Entry>,
+}
+
+impl Report {
+    pub fn add(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.entries.iter().all(Entry::is_success)
+    }
+
+    /// Whether every unsuccessful entry failed by running out of time.
+    pub fn is_timed_out(&self) -> bool {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.is_success())
+            .all(Entry::is_timed_out)
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary {
+            passed: self.count(|status| matches!(status, Status::Pass)),
+            failed: self.count(|status| matches!(status, Status::Fail(_))),
+            errors: self.count(|status| matches!(status, Status::Error(_))),
+            timed_out: self.count(|status| matches!(status, Status::TimedOut(_))),
+            unverified: self.count(|status| matches!(status, Status::Unverified)),
+        }
+    }
+
+    fn count(&self, predicate: fn(&Status) -> bool) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| predicate(&entry.status))
+            .count()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "=== REPORT ===")?;
+
+        for entry in &self.entries {
+            if f.alternate() {
+                writeln!(f, "{entry:#}")?;
+            } else {
+                writeln!(f, "{entry}")?;
+            }
+        }
+
+        let summary = self.summary();
+
+        writeln!(
+            f,
+            "=== {passed} passed, {failed} failed, {errors} errors, {timed_out} timed out, {unverified} unverified ===",
+            passed = summary.passed,
+            failed = summary.failed,
+            errors = summary.errors,
+            timed_out = summary.timed_out,
+            unverified = summary.unverified,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn answer(results: &[&str], timed_out: &[usize]) -> Answer {
+        Answer {
+            year: 2015,
+            day: 1,
+            results: results.iter().map(|result| result.to_string()).collect(),
+            selected_part: None,
+            timings: Default::default(),
+            trace: Vec::new(),
+            timed_out: timed_out.to_vec(),
+        }
+    }
+
+    #[rstest]
+    #[case(&["1", "2"], Some("1\n2"), "pass")]
+    #[case(&["1", "3"], Some("1\n2"), "fail")]
+    #[case(&["1"], Some("1\n2"), "fail")]
+    #[case(&["1", "2"], Some("1"), "pass")]
+    #[case(&["1", "2"], None, "unverified")]
+    fn verify_tests(
+        #[case] results: &[&str],
+        #[case] expected_solution: Option<&str>,
+        #[case] expected_status: &str,
+    ) {
+        let entry = Entry::verify(answer(results, &[]), expected_solution.map(str::to_string));
+
+        assert_eq!(entry.status.label(), expected_status);
+        assert_eq!(entry.is_success(), expected_status != "fail");
+    }
+
+    #[rstest]
+    #[case(&["1", "3"], "1\n2", &["part 2: expected 2, got 3"])]
+    #[case(&["4"], "1\n2", &["part 1: expected 1, got 4", "part 2: expected 2, got no result"])]
+    #[case(&[], "1", &["part 1: expected 1, got no result"])]
+    #[case(&["1", "2"], "1\n2", &[])]
+    fn compare_tests(
+        #[case] results: &[&str],
+        #[case] expected_solution: &str,
+        #[case] expected: &[&str],
+    ) {
+        let mismatches = Mismatch::compare(&answer(results, &[]), expected_solution);
+
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(1, "pass")]
+    #[case(2, "fail")]
+    fn verify_only_selected_part(#[case] selected_part: usize, #[case] expected_status: &str) {
+        let answer = Answer {
+            selected_part: Some(selected_part),
+            ..answer(&["1", "3"], &[])
+        };
+
+        let entry = Entry::verify(answer, Some("1\n2".into()));
+
+        assert_eq!(entry.status.label(), expected_status);
+    }
+
+    #[rstest]
+    #[case(Some("1\n2"), "timeout")]
+    #[case(None, "timeout")]
+    #[case(Some("3\n2"), "fail")]
+    fn timed_out_tests(#[case] expected_solution: Option<&str>, #[case] expected_status: &str) {
+        let entry = Entry::verify(
+            answer(&["1", "timed out"], &[2]),
+            expected_solution.map(str::to_string),
+        );
+
+        assert_eq!(entry.status.label(), expected_status);
+        assert!(!entry.is_success());
+    }
+
+    #[test]
+    fn timed_out_parts_are_not_mismatches() {
+        let mismatches = Mismatch::compare(&answer(&["1", "timed out"], &[2]), "1\n2");
+
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn report_is_timed_out_only_without_other_failures() {
+        let mut report = Report::default();
+        report.add(Entry::verify(answer(&["1", "2"], &[]), Some("1\n2".into())));
+        report.add(Entry::verify(answer(&["timed out", "2"], &[1]), None));
+
+        assert!(report.is_timed_out());
+
+        report.add(Entry::verify(answer(&["1", "3"], &[]), Some("1\n2".into())));
+
+        assert!(!report.is_timed_out());
+    }
+}
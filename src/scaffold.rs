@@ -0,0 +1,469 @@
+use crate::config::PuzzleFiles;
+use itertools::Itertools;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SOURCE_DIR: &str = "src";
+const MAX_LINE_WIDTH: usize = 100;
+
+const DAY_TEMPLATE: &str = r#"use crate::error::ParseContext;
+use crate::puzzle::{answer, puzzle_solver};
+use helpers::Parser;
+
+pub mod helpers;
+
+puzzle_solver!(
+    [{year}, {day}] = {
+        title: "{title}",
+        parts: ["Part 1", "Part 2"],
+        completion: Pending,
+        examples: [("1", ["1", "1"])],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let parser = Parser::default();
+
+            let entries = input
+                .lines()
+                .enumerate()
+                .map(|(idx, line)| parser.parse(line).at_line(idx + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            session.parsed();
+
+            let part_1 = session.solve_part(|| Ok(entries.len()))?;
+
+            let part_2 = session.solve_part(|| Ok(entries.len()))?;
+
+            answer!(part_1, part_2);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::Solver;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("invalid")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
+"#;
+
+const HELPERS_TEMPLATE: &str = r#"use crate::error::{Error, parse_capture};
+use regex::Regex;
+
+pub struct Parser {
+    re: Regex,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            re: Regex::new(r"^(?<value>\d+)$").unwrap(),
+        }
+    }
+}
+
+impl Parser {
+    pub fn parse(&self, input: &str) -> Result<Entry, Error> {
+        let Some(caps) = self.re.captures(input) else {
+            return Err(Error::parse(1, format!("could not parse entry: '{input}'")));
+        };
+
+        let value = parse_capture(&caps, "value")?;
+
+        Ok(Entry { value })
+    }
+}
+
+pub struct Entry {
+    pub value: u32,
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"use crate::puzzle::{Solver, puzzle_solver_selector};
+
+puzzle_solver_selector!();
+"#;
+
+#[derive(Default)]
+pub struct Scaffold {
+    created: Vec<PathBuf>,
+    updated: Vec<PathBuf>,
+}
+
+impl Scaffold {
+    pub fn generate(year: u16, day: u16, title: Option<&str>) -> anyhow::Result<Self> {
+        let mut scaffold = Self::generate_in(Path::new(SOURCE_DIR), year, day, title)?;
+
+        let data_dir = PuzzleFiles::new(year, day).dir().to_path_buf();
+        if !data_dir.exists() {
+            fs::create_dir_all(&data_dir)?;
+            scaffold.created.push(data_dir);
+        }
+
+        Ok(scaffold)
+    }
+
+    fn generate_in(
+        source_dir: &Path,
+        year: u16,
+        day: u16,
+        title: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let mut scaffold = Self::default();
+
+        let year_module = format!("year_{year}");
+        let day_module = format!("day_{day:0>2}");
+
+        let year_dir = source_dir.join(&year_module);
+        let day_dir = year_dir.join(&day_module);
+
+        if day_dir.exists() {
+            anyhow::bail!("{} already exists", day_dir.display());
+        }
+
+        if !year_dir.exists() {
+            fs::create_dir_all(&year_dir)?;
+            scaffold.create(&year_dir.join("mod.rs"), YEAR_TEMPLATE.to_string())?;
+
+            let lib = source_dir.join("lib.rs");
+            let module = format!("pub mod {year_module};");
+            scaffold.update(&lib, |source| add_module(source, &module, "pub mod year_"))?;
+
+            let registry = source_dir.join("registry.rs");
+            let entry = format!("{year} = {year_module}");
+            let import = format!("use crate::{year_module};");
+            scaffold.update(&registry, |source| {
+                let source = add_module(source, &import, "use crate::year_")?;
+                add_macro_entry(&source, "puzzle_registry", &entry)
+            })?;
+        }
+
+        let title = title.map_or_else(|| format!("Day {day}"), str::to_string);
+        let day_source = DAY_TEMPLATE
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""));
+
+        fs::create_dir_all(&day_dir)?;
+        scaffold.create(&day_dir.join("mod.rs"), day_source)?;
+        scaffold.create(&day_dir.join("helpers.rs"), HELPERS_TEMPLATE.to_string())?;
+
+        let year_mod = year_dir.join("mod.rs");
+        let module = format!("pub mod {day_module};");
+        let entry = format!("{day} = {day_module}::Puzzle");
+        scaffold.update(&year_mod, |source| {
+            let source = add_module(source, &module, "pub mod day_")?;
+            add_macro_entry(&source, "puzzle_solver_selector", &entry)
+        })?;
+
+        Ok(scaffold)
+    }
+
+    pub fn created(&self) -> &[PathBuf] {
+        &self.created
+    }
+
+    pub fn updated(&self) -> &[PathBuf] {
+        &self.updated
+    }
+
+    fn create(&mut self, path: &Path, content: String) -> anyhow::Result<()> {
+        fs::write(path, content)?;
+        self.created.push(path.to_path_buf());
+
+        Ok(())
+    }
+
+    fn update<F>(&mut self, path: &Path, edit: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(&str) -> anyhow::Result<String>,
+    {
+        let source = fs::read_to_string(path)?;
+        fs::write(path, edit(&source)?)?;
+
+        if !self.created.iter().any(|created| created == path) {
+            self.updated.push(path.to_path_buf());
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Scaffold {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for path in &self.created {
+            writeln!(f, "created {}", path.display())?;
+        }
+
+        for path in &self.updated {
+            writeln!(f, "updated {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn add_module(source: &str, line: &str, prefix: &str) -> anyhow::Result<String> {
+    let mut lines = source.lines().map(str::to_string).collect_vec();
+
+    if lines.iter().any(|existing| existing == line) {
+        anyhow::bail!("'{line}' is already declared");
+    }
+
+    let siblings = lines
+        .iter()
+        .positions(|existing| existing.starts_with(prefix))
+        .collect_vec();
+
+    let position = match siblings.last() {
+        Some(&last) => siblings
+            .iter()
+            .copied()
+            .find(|&idx| lines[idx].as_str() > line)
+            .unwrap_or(last + 1),
+        None => {
+            let last_use = lines
+                .iter()
+                .rposition(|existing| existing.starts_with("use "));
+            let position = last_use.map_or(0, |idx| idx + 1);
+
+            lines.insert(position, String::new());
+            position + 1
+        }
+    };
+
+    lines.insert(position, line.to_string());
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_macro_entry(source: &str, name: &str, entry: &str) -> anyhow::Result<String> {
+    let invocation = Regex::new(&format!(r"(?s){name}!\((?<entries>.*?)\);"))?;
+
+    let Some(caps) = invocation.captures(source) else {
+        anyhow::bail!("no {name}! invocation found");
+    };
+
+    let span = caps.get(0).unwrap().range();
+
+    let mut entries = caps["entries"]
+        .split(',')
+        .map(str::trim)
+        .filter(|existing| !existing.is_empty())
+        .map(str::to_string)
+        .collect_vec();
+
+    if entries
+        .iter()
+        .any(|existing| entry_key(existing) == entry_key(entry))
+    {
+        anyhow::bail!("{name}! already has an entry for '{entry}'");
+    }
+
+    entries.push(entry.to_string());
+    entries.sort_by_key(|existing| entry_key(existing));
+
+    let single_line = format!("{name}!({});", entries.join(", "));
+
+    let rendered = if single_line.len() <= MAX_LINE_WIDTH {
+        single_line
+    } else {
+        let lines = entries
+            .iter()
+            .map(|existing| format!("    {existing},\n"))
+            .join("");
+        format!("{name}!(\n{lines});")
+    };
+
+    Ok(format!(
+        "{}{rendered}{}",
+        &source[..span.start],
+        &source[span.end..]
+    ))
+}
+
+fn entry_key(entry: &str) -> u16 {
+    entry
+        .split('=')
+        .next()
+        .and_then(|key| key.trim().parse().ok())
+        .unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use rstest::rstest;
+    use std::process::{self, Command};
+    use std::{env, io};
+
+    const YEAR_SOURCE: &str = indoc! {"
+        use crate::puzzle::{Solver, puzzle_solver_selector};
+
+        pub mod day_01;
+        pub mod day_03;
+
+        puzzle_solver_selector!(1 = day_01::Puzzle, 3 = day_03::Puzzle);
+    "};
+
+    #[rstest]
+    #[case("pub mod day_02;", 3)]
+    #[case("pub mod day_00;", 2)]
+    #[case("pub mod day_04;", 4)]
+    fn add_module_keeps_siblings_sorted(#[case] line: &str, #[case] expected_line: usize) {
+        let source = add_module(YEAR_SOURCE, line, "pub mod day_").unwrap();
+
+        assert_eq!(source.lines().nth(expected_line), Some(line));
+        assert_eq!(source.lines().count(), YEAR_SOURCE.lines().count() + 1);
+    }
+
+    #[test]
+    fn add_module_starts_new_block_after_imports() {
+        let source = indoc! {"
+            use crate::puzzle::{Solver, puzzle_solver_selector};
+
+            puzzle_solver_selector!();
+        "};
+
+        let expected = indoc! {"
+            use crate::puzzle::{Solver, puzzle_solver_selector};
+
+            pub mod day_01;
+
+            puzzle_solver_selector!();
+        "};
+
+        let source = add_module(source, "pub mod day_01;", "pub mod day_").unwrap();
+
+        assert_eq!(source, expected);
+    }
+
+    #[test]
+    fn add_module_refuses_existing_line() {
+        let result = add_module(YEAR_SOURCE, "pub mod day_03;", "pub mod day_");
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "2 = day_02::Puzzle",
+        "puzzle_solver_selector!(1 = day_01::Puzzle, 2 = day_02::Puzzle, 3 = day_03::Puzzle);"
+    )]
+    #[case(
+        "4 = day_04::Puzzle",
+        "puzzle_solver_selector!(1 = day_01::Puzzle, 3 = day_03::Puzzle, 4 = day_04::Puzzle);"
+    )]
+    fn add_macro_entry_orders_by_key(#[case] entry: &str, #[case] expected: &str) {
+        let source = add_macro_entry(YEAR_SOURCE, "puzzle_solver_selector", entry).unwrap();
+
+        assert!(source.contains(expected), "{source}");
+    }
+
+    #[test]
+    fn add_macro_entry_fills_empty_invocation() {
+        let source = add_macro_entry(
+            YEAR_TEMPLATE,
+            "puzzle_solver_selector",
+            "1 = day_01::Puzzle",
+        );
+
+        assert!(
+            source
+                .unwrap()
+                .contains("puzzle_solver_selector!(1 = day_01::Puzzle);")
+        );
+    }
+
+    #[test]
+    fn add_macro_entry_wraps_long_invocations() {
+        let source = (4..=9).fold(YEAR_SOURCE.to_string(), |source, day| {
+            let entry = format!("{day} = day_{day:0>2}::Puzzle");
+            add_macro_entry(&source, "puzzle_solver_selector", &entry).unwrap()
+        });
+
+        assert!(source.contains("puzzle_solver_selector!(\n    1 = day_01::Puzzle,\n"));
+        assert!(source.contains("    9 = day_09::Puzzle,\n);"));
+    }
+
+    #[rstest]
+    #[case("puzzle_solver_selector", "3 = day_03::Puzzle")]
+    #[case("puzzle_solver_selector", "3 = day_99::Puzzle")]
+    #[case("puzzle_registry", "2015 = year_2015")]
+    fn add_macro_entry_refuses(#[case] name: &str, #[case] entry: &str) {
+        let result = add_macro_entry(YEAR_SOURCE, name, entry);
+
+        assert!(result.is_err());
+    }
+
+    fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+        fs::create_dir_all(to)?;
+
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            let target = to.join(entry.file_name());
+
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &target)?;
+            } else {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn generated_days_pass_their_tests() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let scratch = env::temp_dir().join(format!("aoc_scaffold_{}", process::id()));
+
+        copy_dir(&root.join(SOURCE_DIR), &scratch.join(SOURCE_DIR)).unwrap();
+        for file in ["Cargo.toml", "Cargo.lock"] {
+            if root.join(file).exists() {
+                fs::copy(root.join(file), scratch.join(file)).unwrap();
+            }
+        }
+
+        let source_dir = scratch.join(SOURCE_DIR);
+        Scaffold::generate_in(&source_dir, 2015, 25, None).unwrap();
+        Scaffold::generate_in(&source_dir, 2016, 1, Some("A \"quoted\" title")).unwrap();
+
+        // Shared between runs so only the scratch crate itself is rebuilt.
+        let target_dir = env::temp_dir().join("aoc_scaffold_target");
+        let output = Command::new(env!("CARGO"))
+            .args([
+                "test",
+                "--offline",
+                "--lib",
+                "--",
+                "year_2015::day_25::",
+                "year_2016::day_01::",
+            ])
+            .current_dir(&scratch)
+            .env("CARGO_TARGET_DIR", target_dir)
+            .output()
+            .unwrap();
+
+        fs::remove_dir_all(&scratch).unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            output.status.success(),
+            "{stdout}{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(stdout.contains("test result: ok. 4 passed"), "{stdout}");
+    }
+}
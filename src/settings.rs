@@ -0,0 +1,341 @@
+use crate::format::Format;
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+const APP_DIR: &str = "aoc";
+const USER_CONFIG: &str = "config";
+const PROJECT_CONFIG: &str = "aoc";
+const EXTENSIONS: &[&str] = &["toml", "json"];
+const DEFAULT_LAYOUT: &str = "{year}/{day}";
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+/// Defaults read from configuration files.
+///
+/// Command line flags take precedence over environment variables, which take
+/// precedence over the project-local file (`aoc.toml` or `aoc.json`, or the
+/// one given with `--config`), which in turn overrides the user-level file
+/// (`$XDG_CONFIG_HOME/aoc/config.toml` or `~/.config/aoc/config.toml`).
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub year: Option<u16>,
+    pub format: Option<Format>,
+    pub timeout: Option<f64>,
+    pub jobs: Option<NonZeroUsize>,
+    pub data_dir: Option<PathBuf>,
+    pub layout: Option<String>,
+    pub base_url: Option<String>,
+    pub session: Option<String>,
+    #[serde(deserialize_with = "deserialize_days")]
+    pub days: BTreeMap<u16, BTreeMap<u16, DaySettings>>,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DaySettings {
+    pub timeout: Option<f64>,
+    pub part: Option<u8>,
+    pub file: Option<PathBuf>,
+}
+
+impl DaySettings {
+    fn merge(self, fallback: Self) -> Self {
+        Self {
+            timeout: self.timeout.or(fallback.timeout),
+            part: self.part.or(fallback.part),
+            file: self.file.or(fallback.file),
+        }
+    }
+}
+
+impl Settings {
+    pub fn discover(project: Option<&Path>) -> anyhow::Result<Self> {
+        let user = match user_config_dir() {
+            Some(dir) => Self::find(&dir.join(APP_DIR), USER_CONFIG)?,
+            None => Self::default(),
+        };
+
+        let project = match project {
+            Some(path) => Self::load(path)?,
+            None => Self::find(Path::new("."), PROJECT_CONFIG)?,
+        };
+
+        Ok(project.merge(user))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("unable to read {}: {error}", path.display()))?;
+
+        let settings = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content)?,
+            Some("json") => serde_json::from_str(&content)?,
+            _ => anyhow::bail!("unsupported configuration format: {}", path.display()),
+        };
+
+        Ok(settings)
+    }
+
+    pub fn install(self) -> anyhow::Result<()> {
+        SETTINGS
+            .set(self)
+            .map_err(|_| anyhow::anyhow!("configuration already loaded"))
+    }
+
+    pub fn day(&self, year: u16, day: u16) -> DaySettings {
+        self.days
+            .get(&year)
+            .and_then(|days| days.get(&day))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn timeout(&self, year: u16, day: u16) -> anyhow::Result<Option<Duration>> {
+        self.day(year, day)
+            .timeout
+            .or(self.timeout)
+            .map(Duration::try_from_secs_f64)
+            .transpose()
+            .map_err(|error| anyhow::anyhow!("invalid timeout for {year}/{day:0>2}: {error}"))
+    }
+
+    pub fn layout(&self) -> &str {
+        self.layout.as_deref().unwrap_or(DEFAULT_LAYOUT)
+    }
+
+    fn find(dir: &Path, name: &str) -> anyhow::Result<Self> {
+        EXTENSIONS
+            .iter()
+            .map(|extension| dir.join(format!("{name}.{extension}")))
+            .find(|path| path.exists())
+            .map_or_else(|| Ok(Self::default()), |path| Self::load(&path))
+    }
+
+    fn merge(self, fallback: Self) -> Self {
+        let mut days = fallback.days;
+
+        for (year, overrides) in self.days {
+            let fallback_days = days.entry(year).or_default();
+
+            for (day, settings) in overrides {
+                let fallback = fallback_days.remove(&day).unwrap_or_default();
+                fallback_days.insert(day, settings.merge(fallback));
+            }
+        }
+
+        Self {
+            year: self.year.or(fallback.year),
+            format: self.format.or(fallback.format),
+            timeout: self.timeout.or(fallback.timeout),
+            jobs: self.jobs.or(fallback.jobs),
+            data_dir: self.data_dir.or(fallback.data_dir),
+            layout: self.layout.or(fallback.layout),
+            base_url: self.base_url.or(fallback.base_url),
+            session: self.session.or(fallback.session),
+            days,
+        }
+    }
+}
+
+pub fn settings() -> &'static Settings {
+    SETTINGS.get_or_init(Settings::default)
+}
+
+/// Reads the `[days.<year>.<day>]` tables, keyed by number so that `04` and `4`
+/// name the same day.
+fn deserialize_days<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<u16, BTreeMap<u16, DaySettings>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let days = BTreeMap::<String, BTreeMap<String, DaySettings>>::deserialize(deserializer)?;
+
+    let mut parsed = BTreeMap::new();
+
+    for (year_key, year_days) in days {
+        let year = parse_key(&year_key, "year")?;
+        let mut parsed_days = BTreeMap::new();
+
+        for (day_key, settings) in year_days {
+            let day = parse_key(&day_key, "day")?;
+
+            if parsed_days.insert(day, settings).is_some() {
+                let message = format!("duplicate settings for {year}/{day:0>2}");
+                return Err(serde::de::Error::custom(message));
+            }
+        }
+
+        if parsed.insert(year, parsed_days).is_some() {
+            return Err(serde::de::Error::custom(format!(
+                "duplicate settings for {year}"
+            )));
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_key<E: serde::de::Error>(key: &str, kind: &str) -> Result<u16, E> {
+    key.parse()
+        .map_err(|_| E::custom(format!("invalid {kind} in days: '{key}'")))
+}
+
+fn user_config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Args;
+    use clap::Parser;
+    use indoc::indoc;
+    use rstest::rstest;
+
+    const USER: &str = indoc! {r#"
+        year = 2015
+        timeout = 30.0
+        jobs = 2
+        layout = "{year}-{day}"
+
+        [days.2015.4]
+        timeout = 5.0
+        part = 1
+        file = "day4.txt"
+    "#};
+
+    const PROJECT: &str = indoc! {r#"
+        year = 2016
+        timeout = 10.0
+
+        [days.2015.4]
+        timeout = 1.0
+
+        [days.2015.7]
+        part = 2
+    "#};
+
+    fn merged() -> Settings {
+        let project: Settings = toml::from_str(PROJECT).unwrap();
+        let user: Settings = toml::from_str(USER).unwrap();
+
+        project.merge(user)
+    }
+
+    #[test]
+    fn project_overrides_user() {
+        let settings = merged();
+
+        assert_eq!(settings.year, Some(2016));
+        assert_eq!(settings.timeout, Some(10.0));
+        assert_eq!(settings.jobs.map(NonZeroUsize::get), Some(2));
+        assert_eq!(settings.layout(), "{year}-{day}");
+    }
+
+    #[test]
+    fn day_settings_merge_per_field() {
+        let settings = merged().day(2015, 4);
+
+        assert_eq!(settings.timeout, Some(1.0));
+        assert_eq!(settings.part, Some(1));
+        assert_eq!(settings.file, Some(PathBuf::from("day4.txt")));
+    }
+
+    #[rstest]
+    #[case(2015, 4, Some(1.0), Some(1))]
+    #[case(2015, 7, Some(10.0), Some(2))]
+    #[case(2015, 8, Some(10.0), None)]
+    #[case(2016, 4, Some(10.0), None)]
+    fn day_override_tests(
+        #[case] year: u16,
+        #[case] day: u16,
+        #[case] expected_timeout: Option<f64>,
+        #[case] expected_part: Option<u8>,
+    ) {
+        let settings = merged();
+
+        assert_eq!(
+            settings.timeout(year, day).unwrap(),
+            expected_timeout.map(Duration::from_secs_f64)
+        );
+        assert_eq!(settings.day(year, day).part, expected_part);
+    }
+
+    #[test]
+    fn day_keys_merge_numerically() {
+        let project: Settings = toml::from_str("[days.2015.04]\npart = 2").unwrap();
+        let user: Settings = serde_json::from_str(
+            r#"{"days": {"02015": {"4": {"timeout": 3.0}, "7": {"part": 1}}}}"#,
+        )
+        .unwrap();
+
+        let settings = project.merge(user);
+
+        assert_eq!(settings.day(2015, 4).part, Some(2));
+        assert_eq!(settings.day(2015, 4).timeout, Some(3.0));
+        assert_eq!(settings.day(2015, 7).part, Some(1));
+        assert_eq!(settings.days.len(), 1);
+        assert_eq!(settings.days[&2015].len(), 2);
+    }
+
+    #[rstest]
+    #[case(r#"{"days": {"2015": {"04": {"part": 1}, "4": {"part": 2}}}}"#)]
+    #[case(r#"{"days": {"2015": {"4": {"part": 1}}, "02015": {"4": {"part": 2}}}}"#)]
+    #[case(r#"{"days": {"2015": {"four": {"part": 1}}}}"#)]
+    #[case(r#"{"days": {"year": {"4": {"part": 1}}}}"#)]
+    fn invalid_day_keys_are_rejected(#[case] content: &str) {
+        assert!(serde_json::from_str::<Settings>(content).is_err());
+    }
+
+    #[test]
+    fn day_keys_match_numerically() {
+        let settings: Settings =
+            serde_json::from_str(r#"{"days": {"2015": {"04": {"part": 2}}}}"#).unwrap();
+
+        assert_eq!(settings.day(2015, 4).part, Some(2));
+        assert_eq!(Settings::default().layout(), DEFAULT_LAYOUT);
+    }
+
+    #[rstest]
+    #[case(&[], Some(2016), None, None)]
+    #[case(&["-y", "2015", "-d", "4"], Some(2015), Some(1), Some("day4.txt"))]
+    #[case(&["-y", "2015", "-d", "4", "-p", "2", "-f", "other.txt"], Some(2015), Some(2), Some("other.txt"))]
+    #[case(&["-y", "2015", "-d", "7"], Some(2015), Some(2), None)]
+    fn command_line_overrides_settings(
+        #[case] flags: &[&str],
+        #[case] expected_year: Option<u16>,
+        #[case] expected_part: Option<u8>,
+        #[case] expected_file: Option<&str>,
+    ) {
+        let args =
+            Args::parse_from(["advent_of_code"].iter().chain(flags)).with_settings(&merged());
+
+        assert_eq!(args.year, expected_year);
+        assert_eq!(args.part, expected_part);
+        assert_eq!(args.input.file_path.as_deref(), expected_file);
+    }
+
+    #[rstest]
+    #[case("timeout = -1.0")]
+    #[case("[days.2015.1]\ntimeout = -1.0")]
+    fn invalid_timeout_tests(#[case] content: &str) {
+        let settings: Settings = toml::from_str(content).unwrap();
+
+        assert!(settings.timeout(2015, 1).is_err());
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(toml::from_str::<Settings>("yaer = 2015").is_err());
+    }
+}
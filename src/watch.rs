@@ -0,0 +1,162 @@
+use crate::error::Error;
+use crate::puzzle::Answer;
+use crate::{RunOptions, registry, run_guarded};
+use itertools::Itertools;
+use std::convert::Infallible;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Watcher {
+    year: u16,
+    day: u16,
+    options: RunOptions,
+    timings: bool,
+    input: Option<String>,
+    answer: Option<Answer>,
+}
+
+impl Watcher {
+    pub fn new(year: u16, day: u16, options: RunOptions, timings: bool) -> Self {
+        Self {
+            year,
+            day,
+            options,
+            timings,
+            input: None,
+            answer: None,
+        }
+    }
+
+    pub fn run<F>(mut self, path: &Path, mut emit: F) -> anyhow::Result<Infallible>
+    where
+        F: FnMut(String),
+    {
+        let Some(solver) = registry::solver(self.year, self.day) else {
+            return Err(Error::NoSolver {
+                year: self.year,
+                day: self.day,
+            }
+            .into());
+        };
+
+        emit(format!("watching {} for changes", path.display()));
+
+        loop {
+            if let Ok(input) = fs::read_to_string(path)
+                && self.input.as_ref() != Some(&input)
+            {
+                let message = match run_guarded(solver.as_ref(), &input, self.options) {
+                    Ok(answer) => self.describe(answer),
+                    Err(error) => format!("Error: {error:#}\n"),
+                };
+
+                self.input = Some(input);
+                emit(message);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn describe(&mut self, answer: Answer) -> String {
+        let description = match &self.answer {
+            None if self.timings => format!("{answer:#}"),
+            None => answer.to_string(),
+            Some(previous) => Self::diff(previous, &answer),
+        };
+
+        self.answer = Some(answer);
+
+        description
+    }
+
+    fn diff(previous: &Answer, current: &Answer) -> String {
+        let changes = current
+            .parts()
+            .map(|(part, result, _)| {
+                let before = previous.results.get(part - 1).map(String::as_str);
+
+                match before {
+                    Some(before) if before == result => {
+                        format!("Part {part}: {result} (unchanged)")
+                    }
+                    Some(before) => format!("Part {part}: {before} -> {result}"),
+                    None => format!("Part {part}: {result} (new)"),
+                }
+            })
+            .join("\n");
+
+        format!(
+            "=== YEAR {year}, DAY {day} (input changed) ===\n{changes}\n",
+            year = current.year,
+            day = current.day
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn answer(results: &[&str], selected_part: Option<usize>) -> Answer {
+        Answer {
+            year: 2015,
+            day: 1,
+            results: results.iter().map(|result| result.to_string()).collect(),
+            selected_part,
+            timings: Default::default(),
+            trace: Vec::new(),
+            timed_out: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn describe_shows_full_answer_then_changes() {
+        let mut watcher = Watcher::new(2015, 1, RunOptions::default(), false);
+
+        let first = watcher.describe(answer(&["3", "1"], None));
+        let second = watcher.describe(answer(&["3", "5"], None));
+
+        assert_eq!(first, "=== YEAR 2015, DAY 1 ===\nPart 1: 3\nPart 2: 1\n");
+        assert_eq!(
+            second,
+            indoc! {"
+                === YEAR 2015, DAY 1 (input changed) ===
+                Part 1: 3 (unchanged)
+                Part 2: 1 -> 5
+            "}
+        );
+    }
+
+    #[test]
+    fn diff_marks_new_parts() {
+        let diff = Watcher::diff(&answer(&["3"], None), &answer(&["4", "1"], None));
+
+        assert_eq!(
+            diff,
+            indoc! {"
+                === YEAR 2015, DAY 1 (input changed) ===
+                Part 1: 3 -> 4
+                Part 2: 1 (new)
+            "}
+        );
+    }
+
+    #[test]
+    fn diff_shows_only_selected_part() {
+        let diff = Watcher::diff(
+            &answer(&["3", "skipped"], Some(1)),
+            &answer(&["4", "skipped"], Some(1)),
+        );
+
+        assert_eq!(
+            diff,
+            "=== YEAR 2015, DAY 1 (input changed) ===\nPart 1: 3 -> 4\n"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error::Error;
+
 #[derive(Default)]
 pub struct Elevator {
     floor: i32,
@@ -35,12 +37,12 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn from(c: char) -> anyhow::Result<Self> {
+    pub fn from(c: char) -> Result<Self, Error> {
         let result = match c {
             '(' => Self::Up,
             ')' => Self::Down,
 
-            other => anyhow::bail!("invalid character '{other}'"),
+            other => return Err(Error::parse(1, format!("invalid character '{other}'"))),
         };
 
         Ok(result)
@@ -1,21 +1,57 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Direction, Elevator};
 use show_option::ShowOption;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 1] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
-            let mut elevator = Elevator::default();
+        title: "Not Quite Lisp",
+        parts: ["Final floor", "First basement position"],
+        examples: [
+            ("(())", ["0", "none"]),
+            ("()()", ["0", "none"]),
+            ("(((", ["3", "none"]),
+            ("(()(()(", ["3", "none"]),
+            ("))(((((", ["3", "1"]),
+            ("())", ["-1", "3"]),
+            ("))(", ["-1", "1"]),
+            (")))", ["-3", "1"]),
+            (")())())", ["-3", "1"]),
+            (")", ["-1", "1"]),
+            ("()())", ["-1", "5"]),
+        ],
 
-            for c in input.chars() {
-                let direction = Direction::from(c)?;
-                elevator.process(direction);
-            }
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let directions = input
+                .chars()
+                .enumerate()
+                .map(|(idx, c)| Direction::from(c).at_column(idx + 1))
+                .collect::<Result<Vec<_>, _>>()?;
 
-            let final_floor = elevator.get_floor();
-            let basement_index = elevator.get_basement_index().show_or("none").to_string();
+            session.parsed();
+
+            let final_floor = session.solve_part(|| {
+                let mut elevator = Elevator::default();
+
+                for direction in &directions {
+                    elevator.process(*direction);
+                }
+
+                anyhow::Ok(elevator.get_floor())
+            })?;
+
+            let basement_index = session.solve_part(|| {
+                let mut elevator = Elevator::default();
+
+                let basement_index = directions.iter().find_map(|direction| {
+                    elevator.process(*direction);
+                    elevator.get_basement_index()
+                });
+
+                anyhow::Ok(basement_index.show_or("none").to_string())
+            })?;
 
             answer!(final_floor, basement_index);
         }
@@ -29,21 +65,9 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    // Part 1
-    #[case("(())", 0, None)]
-    #[case("()()", 0, None)]
     #[case("(((", 3, None)]
-    #[case("(()(()(", 3, None)]
-    #[case("))(((((", 3, Some(1))]
-    #[case("())", -1, Some(3))]
-    #[case("))(", -1, Some(1))]
-    #[case(")))", -3, Some(1))]
-    #[case(")())())", -3, Some(1))]
-    // Part 2
-    #[case(")", -1, Some(1))]
-    #[case("()())", -1, Some(5))]
-    // Other
-    #[case("", 0, None)]
+    #[case("()())\n", -1, Some(5))]
+    #[case("\u{feff}()())\r\n", -1, Some(5))]
     fn positive_tests(
         #[case] input: &str,
         #[case] expected_final_floor: i32,
@@ -58,6 +82,7 @@ mod tests {
     }
 
     #[rstest]
+    #[case("")]
     #[case("))a((")]
     #[case("asdf")]
     fn negative_tests(#[case] input: &str) {
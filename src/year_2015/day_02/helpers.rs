@@ -1,3 +1,4 @@
+use crate::error::{Error, parse_capture};
 use regex::Regex;
 
 pub struct Parser {
@@ -13,14 +14,17 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, text: &str) -> anyhow::Result<Present> {
+    pub fn parse(&self, text: &str) -> Result<Present, Error> {
         let Some(caps) = self.re.captures(text) else {
-            anyhow::bail!("could not parse dimensions: '{text}'");
+            return Err(Error::parse(
+                1,
+                format!("could not parse dimensions: '{text}'"),
+            ));
         };
 
-        let length = caps["length"].parse()?;
-        let width = caps["width"].parse()?;
-        let height = caps["height"].parse()?;
+        let length = parse_capture(&caps, "length")?;
+        let width = parse_capture(&caps, "width")?;
+        let height = parse_capture(&caps, "height")?;
 
         let result = Present {
             length,
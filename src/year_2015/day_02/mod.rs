@@ -1,30 +1,40 @@
+use crate::error::{Error, ParseContext};
 use crate::puzzle::{answer, puzzle_solver};
-use anyhow::Context;
-use helpers::Parser;
+use helpers::{Parser, Present};
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 2] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "I Was Told There Would Be No Math",
+        parts: ["Wrapping paper", "Ribbon"],
+        examples: [
+            ("2x3x4", ["58", "34"]),
+            ("1x1x10", ["43", "14"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
 
             let presents = input
                 .lines()
-                .map(|line| parser.parse(line))
-                .collect::<anyhow::Result<Vec<_>>>()
-                .with_context(|| "could not create presents")?;
+                .enumerate()
+                .map(|(idx, line)| parser.parse(line).at_line(idx + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            session.parsed();
+
+            if presents.is_empty() {
+                return Err(Error::unsolvable("no presents given").into());
+            }
 
-            let (area, ribbon_length) = presents
-                .iter()
-                .map(|present| {
-                    (
-                        present.get_wrapping_paper_area(),
-                        present.get_ribbon_length(),
-                    )
-                })
-                .reduce(|(acc_a, acc_rl), (cur_a, cur_rl)| (acc_a + cur_a, acc_rl + cur_rl))
-                .with_context(|| "could not calculate solution")?;
+            let area = session.solve_part(|| {
+                anyhow::Ok(presents.iter().map(Present::get_wrapping_paper_area).sum::<u32>())
+            })?;
+
+            let ribbon_length = session.solve_part(|| {
+                anyhow::Ok(presents.iter().map(Present::get_ribbon_length).sum::<u32>())
+            })?;
 
             answer!(area, ribbon_length);
         }
@@ -37,20 +47,6 @@ mod tests {
     use crate::puzzle::Solver;
     use rstest::rstest;
 
-    #[rstest]
-    #[case("2x3x4", 58, 34)]
-    #[case("1x1x10", 43, 14)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_area: u32,
-        #[case] expected_ribbon_length: u32,
-    ) {
-        let answer = Puzzle.solve(input).unwrap();
-
-        assert_eq!(answer.results[0], expected_area.to_string());
-        assert_eq!(answer.results[1], expected_ribbon_length.to_string());
-    }
-
     #[rstest]
     #[case("1x1x10x1")]
     #[case("1xAx10")]
@@ -62,4 +58,22 @@ mod tests {
 
         assert!(solution.is_err());
     }
+
+    #[rstest]
+    #[case("2x3x4\n1x1", 2, 1)]
+    #[case("2x3x4\n1x99999999999x1", 2, 3)]
+    fn parse_error_tests(
+        #[case] input: &str,
+        #[case] expected_line: usize,
+        #[case] expected_column: usize,
+    ) {
+        let error = Puzzle.solve(input).unwrap_err();
+
+        let Some(Error::Parse { line, column, .. }) = error.downcast_ref::<Error>() else {
+            panic!("expected a parse error, got: {error}");
+        };
+
+        assert_eq!(*line, expected_line);
+        assert_eq!(*column, expected_column);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::error::Error;
 use std::collections::HashMap;
 
 pub struct PresentDelivery {
@@ -82,14 +83,14 @@ pub enum Movement {
 }
 
 impl Movement {
-    pub fn from_char(c: char) -> anyhow::Result<Self> {
+    pub fn from_char(c: char) -> Result<Self, Error> {
         let movement = match c {
             '<' => Self::Left,
             '>' => Self::Right,
             '^' => Self::Up,
             'v' => Self::Down,
 
-            other => anyhow::bail!("unrecognized character '{other}'"),
+            other => return Err(Error::parse(1, format!("unrecognized character '{other}'"))),
         };
 
         Ok(movement)
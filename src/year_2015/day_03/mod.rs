@@ -1,23 +1,42 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Movement, PresentDelivery};
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 3] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
-            let mut first_year = PresentDelivery::with_deliverers(1);
-            let mut second_year = PresentDelivery::with_deliverers(2);
+        title: "Perfectly Spherical Houses in a Vacuum",
+        parts: ["Houses visited by Santa", "Houses visited with Robo-Santa"],
+        examples: [
+            (">", ["2", "2"]),
+            ("^>v<", ["4", "3"]),
+            ("^v", ["2", "3"]),
+            ("^v^v^v^v^v", ["2", "11"]),
+        ],
 
-            for c in input.chars() {
-                let movement = Movement::from_char(c)?;
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let movements = input
+                .chars()
+                .enumerate()
+                .map(|(idx, c)| Movement::from_char(c).at_column(idx + 1))
+                .collect::<Result<Vec<_>, _>>()?;
 
-                first_year.move_and_add(movement)?;
-                second_year.move_and_add(movement)?;
-            }
+            session.parsed();
 
-            let first_year = first_year.count();
-            let second_year = second_year.count();
+            let deliver = |deliverers: usize| {
+                let mut delivery = PresentDelivery::with_deliverers(deliverers);
+
+                for movement in movements.iter() {
+                    delivery.move_and_add(*movement)?;
+                }
+
+                anyhow::Ok(delivery.count())
+            };
+
+            let first_year = session.solve_part(|| deliver(1))?;
+
+            let second_year = session.solve_part(|| deliver(2))?;
 
             answer!(first_year, second_year);
         }
@@ -31,11 +50,7 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case(">", 2, 2)]
-    #[case("^>v<", 4, 3)]
-    #[case("^v", 2, 3)]
-    #[case("^v^v^v^v^v", 2, 11)]
-    #[case("", 1, 1)]
+    #[case("^v\n", 2, 3)]
     fn positive_tests(
         #[case] input: &str,
         #[case] expected_first_year: u32,
@@ -48,6 +63,7 @@ mod tests {
     }
 
     #[rstest]
+    #[case("")]
     #[case(">^v<a")]
     #[case("asdf")]
     fn negative_tests(#[case] input: &str) {
@@ -1,8 +1,12 @@
+use crate::error::Error;
+use crate::puzzle::Cancellation;
 use anyhow::Context;
 use md5::digest::Output;
 use md5::{Digest, Md5};
 use std::io::Write;
 
+const CANCELLATION_CHECK_INTERVAL: u32 = 0x4000;
+
 #[derive(Default)]
 pub struct Hasher {
     hasher: Md5,
@@ -14,6 +18,7 @@ impl Hasher {
         text: &str,
         starts_with: u32,
         hash_checker: F,
+        cancellation: &Cancellation,
     ) -> anyhow::Result<u32>
     where
         F: Fn(&[u8]) -> bool,
@@ -23,6 +28,10 @@ impl Hasher {
         let mut hash = Output::<Md5>::default();
 
         for number in starts_with..=u32::MAX {
+            if number % CANCELLATION_CHECK_INTERVAL == 0 {
+                cancellation.check()?;
+            }
+
             buffer.clear();
 
             write!(&mut buffer, "{text}{number}").with_context(|| "could not write hash")?;
@@ -37,7 +46,8 @@ impl Hasher {
         }
 
         let Some(number) = result else {
-            anyhow::bail!("could not calculate the suffix for {text}");
+            let message = format!("could not calculate the suffix for {text}");
+            return Err(Error::unsolvable(message).into());
         };
 
         Ok(number)
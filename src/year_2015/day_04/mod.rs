@@ -1,17 +1,29 @@
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::Hasher;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 4] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "The Ideal Stocking Stuffer",
+        parts: ["Lowest number for five zeros", "Lowest number for six zeros"],
+        examples: [
+            ("abcdef", ["609043", "6742839"]),
+            ("pqrstuv", ["1048970", "5714438"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let mut hasher = Hasher::default();
+            let cancellation = session.cancellation();
 
-            let for_5_zeros = hasher.calculate_suffix(input, 0, Hasher::starts_with_5_zeros)?;
+            let for_5_zeros = session.solve_part(|| {
+                hasher.calculate_suffix(input, 0, Hasher::starts_with_5_zeros, &cancellation)
+            })?;
 
-            let for_6_zeros =
-                hasher.calculate_suffix(input, for_5_zeros, Hasher::starts_with_6_zeros)?;
+            let for_6_zeros = session.solve_part(|| {
+                let starts_with = for_5_zeros.solved().copied().unwrap_or(0);
+                hasher.calculate_suffix(input, starts_with, Hasher::starts_with_6_zeros, &cancellation)
+            })?;
 
             answer!(for_5_zeros, for_6_zeros);
         }
@@ -23,18 +35,21 @@ mod tests {
     use super::*;
     use crate::puzzle::Solver;
     use rstest::rstest;
+    use std::time::Duration;
 
     #[rstest]
-    #[case("abcdef", 609043, 6742839)]
-    #[case("pqrstuv", 1048970, 5714438)]
-    fn positive_tests(
+    #[case("abcdef", None, "timed out", "timed out")]
+    #[case("abcdef", Some(1), "timed out", "skipped")]
+    fn timeout_tests(
         #[case] input: &str,
-        #[case] expected_for_5_zeros: u32,
-        #[case] expected_for_6_zeros: u32,
+        #[case] part: Option<usize>,
+        #[case] expected_for_5_zeros: &str,
+        #[case] expected_for_6_zeros: &str,
     ) {
-        let answer = Puzzle.solve(input).unwrap();
+        let session = Session::start(part).with_timeout(Some(Duration::ZERO));
+        let answer = Puzzle.solve_in(input, session).unwrap();
 
-        assert_eq!(answer.results[0], expected_for_5_zeros.to_string());
-        assert_eq!(answer.results[1], expected_for_6_zeros.to_string());
+        assert_eq!(answer.results[0], expected_for_5_zeros);
+        assert_eq!(answer.results[1], expected_for_6_zeros);
     }
 }
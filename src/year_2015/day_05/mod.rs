@@ -1,55 +1,40 @@
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{FirstYearCriteria, NiceStringValidator, SecondYearCriteria};
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 5] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
-            let mut first_year = 0u32;
-            let mut second_year = 0u32;
-
-            for line in input.lines() {
-                if NiceStringValidator::validate::<FirstYearCriteria>(line) {
-                    first_year += 1;
-                }
-
-                if NiceStringValidator::validate::<SecondYearCriteria>(line) {
-                    second_year += 1;
-                }
-            }
+        title: "Doesn't He Have Intern-Elves For This?",
+        parts: ["Nice strings", "Nice strings with new rules"],
+        examples: [
+            ("ugknbfddgicrmopn", ["1", "0"]),
+            ("aaa", ["1", "0"]),
+            ("jchzalrnumimnmhp", ["0", "0"]),
+            ("haegwjzuvuyypxyu", ["0", "0"]),
+            ("dvszwmarrgswjxmb", ["0", "0"]),
+            ("qjhvhtzxzqqjkmpb", ["0", "1"]),
+            ("xxyxx", ["0", "1"]),
+            ("uurcxstgmygtbstg", ["0", "0"]),
+            ("ieodomkazucvgmuy", ["0", "0"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let first_year = session.solve_part(|| {
+                let nice = input
+                    .lines()
+                    .filter(|line| NiceStringValidator::validate::<FirstYearCriteria>(line));
+                Ok(nice.count())
+            })?;
+
+            let second_year = session.solve_part(|| {
+                let nice = input
+                    .lines()
+                    .filter(|line| NiceStringValidator::validate::<SecondYearCriteria>(line));
+                Ok(nice.count())
+            })?;
 
             answer!(first_year, second_year);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::puzzle::Solver;
-    use rstest::rstest;
-
-    #[rstest]
-    // Part 1
-    #[case("ugknbfddgicrmopn", 1, 0)]
-    #[case("aaa", 1, 0)]
-    #[case("jchzalrnumimnmhp", 0, 0)]
-    #[case("haegwjzuvuyypxyu", 0, 0)]
-    #[case("dvszwmarrgswjxmb", 0, 0)]
-    // Part 2
-    #[case("qjhvhtzxzqqjkmpb", 0, 1)]
-    #[case("xxyxx", 0, 1)]
-    #[case("uurcxstgmygtbstg", 0, 0)]
-    #[case("ieodomkazucvgmuy", 0, 0)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_first_year: u32,
-        #[case] expected_second_year: u32,
-    ) {
-        let answer = Puzzle.solve(input).unwrap();
-
-        assert_eq!(answer.results[0], expected_first_year.to_string());
-        assert_eq!(answer.results[1], expected_second_year.to_string());
-    }
-}
@@ -1,7 +1,9 @@
-use anyhow::Context;
+use crate::error::{Error, parse_capture};
 use regex::Regex;
 use std::ops::RangeInclusive;
 
+const GRID_SIZE: usize = 1000;
+
 pub struct Parser {
     pattern: Regex,
 }
@@ -15,16 +17,29 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, line: &str) -> anyhow::Result<Instruction> {
-        let caps = self
-            .pattern
-            .captures(line)
-            .with_context(|| format!("invalid instruction pattern: {line}"))?;
+    pub fn parse(&self, line: &str) -> Result<Instruction, Error> {
+        let Some(caps) = self.pattern.captures(line) else {
+            return Err(Error::parse(
+                1,
+                format!("invalid instruction pattern: {line}"),
+            ));
+        };
 
-        let from_x = caps["from_x"].parse()?;
-        let from_y = caps["from_y"].parse()?;
-        let to_x = caps["to_x"].parse()?;
-        let to_y = caps["to_y"].parse()?;
+        let from_x = parse_capture(&caps, "from_x")?;
+        let from_y = parse_capture(&caps, "from_y")?;
+        let to_x = parse_capture(&caps, "to_x")?;
+        let to_y = parse_capture(&caps, "to_y")?;
+
+        if to_x >= GRID_SIZE || to_y >= GRID_SIZE {
+            return Err(Error::parse(
+                1,
+                format!("range outside of the grid: {line}"),
+            ));
+        }
+
+        if from_x > to_x || from_y > to_y {
+            return Err(Error::parse(1, format!("range is reversed: {line}")));
+        }
 
         let range = Range {
             from_x,
@@ -61,12 +76,17 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    fn from(instruction: &str, range: Range) -> anyhow::Result<Self> {
+    fn from(instruction: &str, range: Range) -> Result<Self, Error> {
         let instruction = match instruction {
             "turn on" => Self::TurnOn(range),
             "turn off" => Self::TurnOff(range),
             "toggle" => Self::Toggle(range),
-            _ => anyhow::bail!("unknown instruction: {instruction}"),
+            _ => {
+                return Err(Error::parse(
+                    1,
+                    format!("unknown instruction: {instruction}"),
+                ));
+            }
         };
 
         Ok(instruction)
@@ -80,7 +100,7 @@ pub struct LightArray<T> {
 impl<T: Default + Clone> Default for LightArray<T> {
     fn default() -> Self {
         Self {
-            array: vec![vec![T::default(); 1000]; 1000],
+            array: vec![vec![T::default(); GRID_SIZE]; GRID_SIZE],
         }
     }
 }
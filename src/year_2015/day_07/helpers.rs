@@ -1,4 +1,5 @@
 use crate::dictionary::{Dictionary, DictionaryIdx};
+use crate::error::Error;
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -19,7 +20,7 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<(String, Node)> {
+    pub fn parse(&self, input: &str) -> Result<(String, Node), Error> {
         if let Some(caps) = self.re_simple.captures(input) {
             Self::parse_simple_node(&caps)
         } else if let Some(caps) = self.re_gate_one_input.captures(input) {
@@ -27,55 +28,58 @@ impl Parser {
         } else if let Some(caps) = self.re_gate_double_input.captures(input) {
             Self::parse_gate_double_input(&caps)
         } else {
-            anyhow::bail!("invalid input: {}", input);
+            Err(Error::parse(1, format!("invalid input: {input}")))
         }
     }
 
-    fn parse_input(input: &str) -> anyhow::Result<Input> {
-        let input = match input.parse() {
+    fn parse_input(input: &str) -> Input {
+        match input.parse() {
             Ok(value) => Input::Value(value),
             Err(_) => Input::Node(String::from(input)),
-        };
-
-        Ok(input)
+        }
     }
 
-    fn parse_simple_node(caps: &regex::Captures) -> anyhow::Result<(String, Node)> {
+    fn parse_simple_node(caps: &regex::Captures) -> Result<(String, Node), Error> {
         let name = caps["name"].to_string();
-        let input = Self::parse_input(&caps["input"])?;
+        let input = Self::parse_input(&caps["input"]);
 
         Ok((name, Node::Simple(input)))
     }
 
-    fn parse_gate_one_input(caps: &regex::Captures) -> anyhow::Result<(String, Node)> {
+    fn parse_gate_one_input(caps: &regex::Captures) -> Result<(String, Node), Error> {
         let name = caps["name"].to_string();
         let instruction = &caps["instruction"];
-        let input = Self::parse_input(&caps["input"])?;
+        let input = Self::parse_input(&caps["input"]);
 
         let result = match instruction {
             "NOT" => (name, Node::Negation(input)),
-            other => anyhow::bail!("invalid instruction: {other}"),
+            other => return Err(Self::invalid_instruction(caps, other)),
         };
 
         Ok(result)
     }
 
-    fn parse_gate_double_input(caps: &regex::Captures) -> anyhow::Result<(String, Node)> {
+    fn parse_gate_double_input(caps: &regex::Captures) -> Result<(String, Node), Error> {
         let name = caps["name"].to_string();
         let instruction = &caps["instruction"];
-        let left = Self::parse_input(&caps["left"])?;
-        let right = Self::parse_input(&caps["right"])?;
+        let left = Self::parse_input(&caps["left"]);
+        let right = Self::parse_input(&caps["right"]);
 
         let result = match instruction {
             "AND" => (name, Node::AndGate(left, right)),
             "OR" => (name, Node::OrGate(left, right)),
             "RSHIFT" => (name, Node::RightShift(left, right)),
             "LSHIFT" => (name, Node::LeftShift(left, right)),
-            other => anyhow::bail!("invalid instruction: {other}"),
+            other => return Err(Self::invalid_instruction(caps, other)),
         };
 
         Ok(result)
     }
+
+    fn invalid_instruction(caps: &regex::Captures, instruction: &str) -> Error {
+        let column = caps.name("instruction").map_or(0, |m| m.start()) + 1;
+        Error::parse(column, format!("invalid instruction: {instruction}"))
+    }
 }
 
 pub enum Node {
@@ -110,7 +114,7 @@ impl Circuit {
     fn get_node_index(&self, name: &str) -> anyhow::Result<NodeIdx> {
         self.node_dict
             .map_to_idx(name)
-            .ok_or_else(|| anyhow::anyhow!("invalid node name: {name}"))
+            .ok_or_else(|| Error::unsolvable(format!("invalid node name: {name}")).into())
     }
 
     pub fn get_node_value(&self, name: &str) -> anyhow::Result<u16> {
@@ -125,10 +129,9 @@ impl Circuit {
             return Ok(*value);
         }
 
-        let node = self
-            .nodes
-            .get(&idx)
-            .ok_or_else(|| anyhow::anyhow!("invalid circuit definition (no '{name}')"))?;
+        let node = self.nodes.get(&idx).ok_or_else(|| {
+            Error::unsolvable(format!("invalid circuit definition (no '{name}')"))
+        })?;
 
         let value = match node {
             Node::Simple(input) => self.solve_input_value(cache, input)?,
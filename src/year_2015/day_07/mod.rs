@@ -1,24 +1,71 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Circuit, Input, Node, Parser};
+use indoc::indoc;
 
-mod helpers;
+pub mod helpers;
+
+const INPUT_AND: &str = indoc! {
+    "b AND c -> a
+    123 -> b
+    456 -> c"
+};
+
+const INPUT_OR: &str = indoc! {
+    "b OR c -> a
+    123 -> b
+    456 -> c"
+};
+
+const INPUT_RSHIFT: &str = indoc! {
+    "b RSHIFT 2 -> a
+    123 -> b"
+};
+
+const INPUT_LSHIFT: &str = indoc! {
+    "b LSHIFT 2 -> a
+    123 -> b"
+};
+
+const INPUT_NOT: &str = indoc! {
+    "NOT b -> a
+    123 -> b"
+};
 
 puzzle_solver!(
     [2015, 7] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Some Assembly Required",
+        parts: ["Signal on wire a", "Signal on wire a after override"],
+        examples: [
+            (INPUT_AND, ["72", "72"]),
+            (INPUT_OR, ["507", "507"]),
+            (INPUT_RSHIFT, ["30", "7"]),
+            (INPUT_LSHIFT, ["492", "1968"]),
+            (INPUT_NOT, ["65412", "123"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
             let mut circuit = Circuit::default();
 
-            for line in input.lines() {
-                let (name, node) = parser.parse(line)?;
+            for (idx, line) in input.lines().enumerate() {
+                let (name, node) = parser.parse(line).at_line(idx + 1)?;
                 circuit.set(&name, node);
             }
 
-            let first_value_of_wire_a = circuit.get_node_value("a")?;
+            session.parsed();
+
+            let first_value_of_wire_a = session.solve_part(|| circuit.get_node_value("a"))?;
 
-            circuit.set("b", Node::Simple(Input::Value(first_value_of_wire_a)));
+            let second_value_of_wire_a = session.solve_part(|| {
+                let value_of_wire_a = match first_value_of_wire_a.solved() {
+                    Some(value) => *value,
+                    None => circuit.get_node_value("a")?,
+                };
 
-            let second_value_of_wire_a = circuit.get_node_value("a")?;
+                circuit.set("b", Node::Simple(Input::Value(value_of_wire_a)));
+                circuit.get_node_value("a")
+            })?;
 
             answer!(first_value_of_wire_a, second_value_of_wire_a);
         }
@@ -29,56 +76,20 @@ puzzle_solver!(
 mod tests {
     use super::*;
     use crate::puzzle::Solver;
-    use indoc::indoc;
     use rstest::rstest;
 
-    const INPUT_AND: &str = indoc! {
-        "b AND c -> a
-        123 -> b
-        456 -> c"
-    };
-
-    const INPUT_OR: &str = indoc! {
-        "b OR c -> a
-        123 -> b
-        456 -> c"
-    };
-
-    const INPUT_RSHIFT: &str = indoc! {
-        "b RSHIFT 2 -> a
-        123 -> b"
-    };
-
-    const INPUT_LSHIFT: &str = indoc! {
-        "b LSHIFT 2 -> a
-        123 -> b"
-    };
-
-    const INPUT_NOT: &str = indoc! {
-        "NOT b -> a
-        123 -> b"
-    };
-
     #[rstest]
-    #[case(INPUT_AND, 72, 72)]
-    #[case(INPUT_OR, 507, 507)]
-    #[case(INPUT_RSHIFT, 30, 7)]
-    #[case(INPUT_LSHIFT, 492, 1968)]
-    #[case(INPUT_NOT, 65412, 123)]
-    fn positive_tests(
+    #[case(INPUT_LSHIFT, 1, "492", "skipped")]
+    #[case(INPUT_LSHIFT, 2, "skipped", "1968")]
+    fn part_selection_tests(
         #[case] input: &str,
-        #[case] expected_first_value_of_wire_a: u16,
-        #[case] expected_second_value_of_wire_a: u16,
+        #[case] part: usize,
+        #[case] expected_first_value_of_wire_a: &str,
+        #[case] expected_second_value_of_wire_a: &str,
     ) {
-        let solution = Puzzle.solve(input).unwrap();
-
-        assert_eq!(
-            solution.results[0],
-            expected_first_value_of_wire_a.to_string()
-        );
-        assert_eq!(
-            solution.results[1],
-            expected_second_value_of_wire_a.to_string()
-        );
+        let solution = Puzzle.solve_part(input, part).unwrap();
+
+        assert_eq!(solution.results[0], expected_first_value_of_wire_a);
+        assert_eq!(solution.results[1], expected_second_value_of_wire_a);
     }
 }
@@ -1,13 +1,15 @@
+use crate::error::Error;
 use std::str::Bytes;
 
 pub struct StringParser;
 
 impl StringParser {
-    pub fn get_unescaped_string_as_u8(input: &str) -> anyhow::Result<Vec<u8>> {
-        let mut buffer = if input.starts_with("\"") && input.ends_with("\"") {
+    pub fn get_unescaped_string_as_u8(input: &str) -> Result<Vec<u8>, Error> {
+        let mut buffer = if input.len() >= 2 && input.starts_with('"') && input.ends_with('"') {
             input[1..input.len() - 1].bytes()
         } else {
-            anyhow::bail!("input should start and and with '\"' ({input})")
+            let message = format!("input should start and and with '\"' ({input})");
+            return Err(Error::parse(1, message));
         };
 
         let mut parsed = Vec::new();
@@ -18,6 +20,8 @@ impl StringParser {
                 continue;
             }
 
+            let column = input.len() - buffer.len() - 1;
+
             let byte = match buffer.next() {
                 Some(b'\\') => Some(b'\\'),
                 Some(b'"') => Some(b'"'),
@@ -26,7 +30,8 @@ impl StringParser {
             };
 
             let Some(byte) = byte else {
-                anyhow::bail!("invalid escape sequence in {input}");
+                let message = format!("invalid escape sequence in {input}");
+                return Err(Error::parse(column, message));
             };
 
             parsed.push(byte);
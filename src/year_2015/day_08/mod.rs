@@ -1,48 +1,45 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::StringParser;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 8] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Matchsticks",
+        parts: ["Code minus memory characters", "Encoded minus code characters"],
+        examples: [
+            (r#""""#, ["2", "4"]),
+            (r#""abc""#, ["2", "4"]),
+            (r#""aaa\"aaa""#, ["3", "6"]),
+            (r#""\x27""#, ["5", "5"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let mut raw_length = 0;
             let mut parsed_length = 0;
-            let mut encoded_length = 0;
 
-            for line in input.lines() {
+            for (idx, line) in input.lines().enumerate() {
                 raw_length += line.len();
-                parsed_length += StringParser::get_unescaped_string_as_u8(line)?.len();
-                encoded_length += format!("\"{}\"", line.escape_default()).len();
+                parsed_length += StringParser::get_unescaped_string_as_u8(line)
+                    .at_line(idx + 1)?
+                    .len();
             }
 
-            let raw_vs_parsed_diff = raw_length - parsed_length;
-            let encoded_vs_raw_diff = encoded_length - raw_length;
+            session.parsed();
+
+            let raw_vs_parsed_diff = session.solve_part(|| Ok(raw_length - parsed_length))?;
+
+            let encoded_vs_raw_diff = session.solve_part(|| {
+                let encoded_length: usize = input
+                    .lines()
+                    .map(|line| format!("\"{}\"", line.escape_default()).len())
+                    .sum();
+
+                Ok(encoded_length - raw_length)
+            })?;
 
             answer!(raw_vs_parsed_diff, encoded_vs_raw_diff);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use crate::puzzle::Solver;
-    use crate::year_2015::day_08::Puzzle;
-    use rstest::rstest;
-
-    #[rstest]
-    #[case(stringify!(""), 2, 4)]
-    #[case(stringify!("abc"), 2, 4)]
-    #[case(stringify!("aaa\"aaa"), 3, 6)]
-    #[case(stringify!("\x27"), 5, 5)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_first_year: u32,
-        #[case] expected_second_year: u32,
-    ) {
-        let answer = Puzzle.solve(input).unwrap();
-
-        assert_eq!(answer.results[0], expected_first_year.to_string());
-        assert_eq!(answer.results[1], expected_second_year.to_string());
-    }
-}
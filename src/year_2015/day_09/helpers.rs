@@ -1,5 +1,5 @@
 use crate::dictionary::{Dictionary, DictionaryIdx};
-use anyhow::anyhow;
+use crate::error::{Error, parse_capture};
 use itertools::Itertools;
 use regex::Regex;
 use std::cmp::{max, min};
@@ -18,14 +18,14 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Distance> {
+    pub fn parse(&self, input: &str) -> Result<Distance, Error> {
         let Some(caps) = self.re.captures(input) else {
-            anyhow::bail!("invalid input '{input}'");
+            return Err(Error::parse(1, format!("invalid input '{input}'")));
         };
 
         let location_1 = caps["location1"].to_string();
         let location_2 = caps["location2"].to_string();
-        let distance = caps["distance"].parse()?;
+        let distance = parse_capture(&caps, "distance")?;
 
         let result = Distance {
             location_1,
@@ -76,11 +76,11 @@ impl TravelPlanner {
                 let to = pair[1];
 
                 sum_distance += self.distances.get(&(from, to)).ok_or_else(|| {
-                    anyhow!(
+                    Error::unsolvable(format!(
                         "could not find route from {loc_1} to {loc_2}",
                         loc_1 = self.locations.map_to_name(from),
                         loc_2 = self.locations.map_to_name(to)
-                    )
+                    ))
                 })?;
             }
 
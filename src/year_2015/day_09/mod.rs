@@ -1,54 +1,47 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Parser, TravelPlanner};
+use indoc::indoc;
 use show_option::ShowOption;
 
-mod helpers;
+pub mod helpers;
+
+const INPUT: &str = indoc! {
+    "London to Dublin = 464
+    London to Belfast = 518
+    Dublin to Belfast = 141"
+};
 
 puzzle_solver!(
     [2015, 9] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "All in a Single Night",
+        parts: ["Shortest route", "Longest route"],
+        examples: [
+            ("A to B = 10", ["10", "10"]),
+            (INPUT, ["605", "982"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
             let mut planner = TravelPlanner::default();
 
-            for line in input.lines() {
-                let distance = parser.parse(line)?;
+            for (idx, line) in input.lines().enumerate() {
+                let distance = parser.parse(line).at_line(idx + 1)?;
                 planner.add(distance);
             }
 
-            let solution = planner.calculate_distances()?;
+            session.parsed();
+
+            let [min_distance, max_distance] = session.solve_parts(|| {
+                let solution = planner.calculate_distances()?;
 
-            let min_distance = solution.min_dist.show_or("none").to_string();
-            let max_distance = solution.max_dist.show_or("none").to_string();
+                Ok([
+                    solution.min_dist.show_or("none").to_string(),
+                    solution.max_dist.show_or("none").to_string(),
+                ])
+            })?;
 
             answer!(min_distance, max_distance);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::puzzle::Solver;
-    use indoc::indoc;
-    use rstest::rstest;
-
-    const INPUT: &str = indoc! {
-        "London to Dublin = 464
-        London to Belfast = 518
-        Dublin to Belfast = 141"
-    };
-
-    #[rstest]
-    #[case("A to B = 10", 10, 10)]
-    #[case(INPUT, 605, 982)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_min_distance: u16,
-        #[case] expected_max_distance: u16,
-    ) {
-        let solution = Puzzle.solve(input).unwrap();
-
-        assert_eq!(solution.results[0], expected_min_distance.to_string());
-        assert_eq!(solution.results[1], expected_max_distance.to_string());
-    }
-}
@@ -1,21 +1,38 @@
+use crate::error::Error;
+use crate::puzzle::Cancellation;
+
 pub struct LookAndSay;
 
 impl LookAndSay {
-    pub fn run(input: &str, iterations: usize) -> String {
+    pub fn validate(input: &str) -> Result<(), Error> {
+        match input.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((idx, c)) => Err(Error::parse(idx + 1, format!("not a digit: '{c}'"))),
+            None if input.is_empty() => Err(Error::parse(1, "no digits given")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn run(
+        input: &str,
+        iterations: usize,
+        cancellation: &Cancellation,
+    ) -> Result<String, Error> {
         let mut text = input.to_string();
 
         for _ in 0..iterations {
+            cancellation.check()?;
+
             let mut buffer = Buffer::default();
 
             let mut prev = None;
             let mut count = 0;
 
             for c in text.chars() {
-                if let Some(prev) = prev {
-                    if c != prev {
-                        buffer.append(count, prev);
-                        count = 0;
-                    }
+                if let Some(prev) = prev
+                    && c != prev
+                {
+                    buffer.append(count, prev);
+                    count = 0;
                 }
                 prev = Some(c);
                 count += 1;
@@ -27,7 +44,7 @@ impl LookAndSay {
             text = buffer.text;
         }
 
-        text
+        Ok(text)
     }
 }
 
@@ -38,7 +55,7 @@ struct Buffer {
 
 impl Buffer {
     fn append(&mut self, count: u32, c: char) {
-        self.text.push(char::from_digit(count, 10).unwrap());
+        self.text.push_str(&count.to_string());
         self.text.push(c);
     }
 }
@@ -1,39 +1,39 @@
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::LookAndSay;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 10] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
-            let after_40 = LookAndSay::run(input, 40);
-            let after_50 = LookAndSay::run(&after_40, 10);
+        title: "Elves Look, Elves Say",
+        parts: ["Length after 40 rounds", "Length after 50 rounds"],
+        examples: [
+            ("1", ["82350", "1166642"]),
+            ("22", ["2", "2"]),
+        ],
 
-            let length_after_40 = after_40.len();
-            let length_after_50 = after_50.len();
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            LookAndSay::validate(input)?;
+
+            session.parsed();
+
+            let mut after_40 = None;
+            let cancellation = session.cancellation();
+
+            let length_after_40 = session.solve_part(|| {
+                let text = after_40.insert(LookAndSay::run(input, 40, &cancellation)?);
+                Ok(text.len())
+            })?;
+
+            let length_after_50 = session.solve_part(|| {
+                let after_40 = match after_40 {
+                    Some(text) => text,
+                    None => LookAndSay::run(input, 40, &cancellation)?,
+                };
+                Ok(LookAndSay::run(&after_40, 10, &cancellation)?.len())
+            })?;
 
             answer!(length_after_40, length_after_50);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::puzzle::Solver;
-    use rstest::rstest;
-
-    #[rstest]
-    #[case("1", 82350, 1166642)]
-    #[case("22", 2, 2)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_len_after_40: usize,
-        #[case] expected_len_after_50: usize,
-    ) {
-        let solution = Puzzle.solve(input).unwrap();
-
-        assert_eq!(solution.results[0], expected_len_after_40.to_string());
-        assert_eq!(solution.results[1], expected_len_after_50.to_string());
-    }
-}
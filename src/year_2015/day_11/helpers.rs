@@ -1,14 +1,29 @@
+use crate::error::Error;
+use crate::puzzle::Cancellation;
+
 type ValidPassword = bool;
 
 pub struct PasswordGenerator;
 
 impl PasswordGenerator {
-    pub fn find_next_valid_password(password: &str) -> anyhow::Result<String> {
+    pub fn find_next_valid_password(
+        password: &str,
+        cancellation: &Cancellation,
+    ) -> anyhow::Result<String> {
         let mut password: Vec<char> = password.chars().collect();
 
-        match Self::increment_password(&mut password, 0, false) {
+        if let Some(idx) = password.iter().position(|c| !c.is_ascii_lowercase()) {
+            let message = format!("invalid character: {}", password[idx]);
+            return Err(Error::parse(idx + 1, message).into());
+        }
+
+        if password.is_empty() {
+            return Err(Error::parse(1, "empty password").into());
+        }
+
+        match Self::increment_password(&mut password, 0, false, cancellation) {
             Ok(true) => Ok(password.into_iter().collect()),
-            Ok(false) => anyhow::bail!("no correct password found"),
+            Ok(false) => Err(Error::unsolvable("no correct password found").into()),
             Err(e) => Err(e),
         }
     }
@@ -17,12 +32,17 @@ impl PasswordGenerator {
         password: &mut [char],
         idx: usize,
         skip: bool,
+        cancellation: &Cancellation,
     ) -> anyhow::Result<ValidPassword> {
         loop {
+            if idx + 2 == password.len() {
+                cancellation.check()?;
+            }
+
             if idx < password.len() - 1 {
                 let skip = skip || Self::is_disallowed_char(password[idx]);
 
-                match Self::increment_password(password, idx + 1, skip) {
+                match Self::increment_password(password, idx + 1, skip, cancellation) {
                     Ok(false) => (),
                     other => return other,
                 }
@@ -1,13 +1,33 @@
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::PasswordGenerator;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 11] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
-            let next_password = PasswordGenerator::find_next_valid_password(input)?;
-            let another_password = PasswordGenerator::find_next_valid_password(&next_password)?;
+        title: "Corporate Policy",
+        parts: ["Next password", "Password after that"],
+        examples: [
+            ("abcdefgh", ["abcdffaa", "abcdffbb"]),
+            ("ghijklmn", ["ghjaabcc", "ghjbbcdd"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let mut next = None;
+            let cancellation = session.cancellation();
+
+            let next_password = session.solve_part(|| {
+                let password = PasswordGenerator::find_next_valid_password(input, &cancellation)?;
+                Ok(next.insert(password).clone())
+            })?;
+
+            let another_password = session.solve_part(|| {
+                let next = match next {
+                    Some(password) => password,
+                    None => PasswordGenerator::find_next_valid_password(input, &cancellation)?,
+                };
+                PasswordGenerator::find_next_valid_password(&next, &cancellation)
+            })?;
 
             answer!(next_password, another_password);
         }
@@ -21,8 +41,7 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case("abcdefgh", "abcdffaa", "abcdffbb")]
-    #[case("ghijklmn", "ghjaabcc", "ghjbbcdd")]
+    #[case("abcdefgh\n", "abcdffaa", "abcdffbb")]
     fn positive_tests(
         #[case] input: &str,
         #[case] expected_next_password: &str,
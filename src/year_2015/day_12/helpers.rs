@@ -1,3 +1,4 @@
+use crate::error::Error;
 use serde_json::{Map, Value};
 
 #[derive(Default)]
@@ -12,44 +13,51 @@ impl JsonInterpreter {
         Self { excluded_string }
     }
 
-    pub fn add_numbers(&self, input: &Value) -> i64 {
+    pub fn add_numbers(&self, input: &Value) -> Result<i64, Error> {
         match input {
-            Value::Number(num) => num.as_i64().unwrap(),
+            Value::Number(num) => num
+                .as_i64()
+                .ok_or_else(|| Error::unsolvable(format!("not an integer: {num}"))),
 
             Value::Array(array) => self.process_array(array),
 
             Value::Object(object) => self.process_object(object),
 
-            _ => 0,
+            _ => Ok(0),
         }
     }
 
-    fn process_array(&self, array: &Vec<Value>) -> i64 {
+    fn process_array(&self, array: &Vec<Value>) -> Result<i64, Error> {
         let mut sum = 0;
 
         for value in array {
-            sum += self.add_numbers(value);
+            sum = Self::add(sum, self.add_numbers(value)?)?;
         }
 
-        sum
+        Ok(sum)
     }
 
-    fn process_object(&self, object: &Map<String, Value>) -> i64 {
+    fn process_object(&self, object: &Map<String, Value>) -> Result<i64, Error> {
         let mut sum = 0;
 
         for value in object.values() {
             match value {
                 Value::String(text) => {
-                    if let Some(excluded) = &self.excluded_string {
-                        if text == excluded {
-                            return 0;
-                        }
+                    if let Some(excluded) = &self.excluded_string
+                        && text == excluded
+                    {
+                        return Ok(0);
                     }
                 }
-                other => sum += self.add_numbers(other),
+                other => sum = Self::add(sum, self.add_numbers(other)?)?,
             }
         }
 
-        sum
+        Ok(sum)
+    }
+
+    fn add(sum: i64, value: i64) -> Result<i64, Error> {
+        sum.checked_add(value)
+            .ok_or_else(|| Error::unsolvable("sum overflows"))
     }
 }
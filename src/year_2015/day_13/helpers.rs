@@ -1,5 +1,5 @@
 use crate::dictionary::{Dictionary, DictionaryIdx};
-use anyhow::anyhow;
+use crate::error::{Error, parse_capture};
 use itertools::Itertools;
 use regex::Regex;
 use std::cmp::max;
@@ -16,20 +16,20 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Relation> {
+    pub fn parse(&self, input: &str) -> Result<Relation, Error> {
         let Some(caps) = self.re.captures(input) else {
-            anyhow::bail!("could not parse input '{input}'");
+            return Err(Error::parse(1, format!("could not parse input '{input}'")));
         };
 
         let person = caps["person"].to_string();
         let neighbour = caps["neighbour"].to_string();
-        let amount = caps["amount"].parse()?;
+        let amount = parse_capture(&caps, "amount")?;
         let instruction = &caps["instruction"];
 
         let happiness_gain: i32 = match instruction {
             "gain" => amount,
             "lose" => -amount,
-            other => anyhow::bail!("invalid instruction: {other}"),
+            other => return Err(Error::parse(1, format!("invalid instruction: {other}"))),
         };
 
         let result = Relation {
@@ -68,26 +68,28 @@ impl SeatingArrangement {
             .or_insert(relation.happiness_gain);
     }
 
-    pub fn calculate_happiness(&mut self) -> anyhow::Result<Solution> {
-        let mut solution = Solution::default();
+    /// Finds the happiest seating around the table. Adding yourself, with no
+    /// feelings either way, breaks the circle between the last and first person.
+    pub fn max_happiness(&self, add_yourself: bool) -> anyhow::Result<Option<i32>> {
+        let mut max_happiness = None;
 
         for permutation in (0..self.people.len()).permutations(self.people.len()) {
             let mut sum = 0;
-            let mut sum_with_add = 0;
 
-            for (first, second) in permutation.iter().circular_tuple_windows() {
+            for (first, second) in permutation.iter().tuple_windows() {
                 sum += self.calculate_pair_happiness(*first, *second)?;
             }
 
-            for (first, second) in permutation.iter().tuple_windows() {
-                sum_with_add += self.calculate_pair_happiness(*first, *second)?;
+            if !add_yourself
+                && let (Some(first), Some(last)) = (permutation.first(), permutation.last())
+            {
+                sum += self.calculate_pair_happiness(*last, *first)?;
             }
 
-            solution.solve_max_happiness(sum);
-            solution.solve_max_happiness_with_add(sum_with_add);
+            max_happiness = max(max_happiness, Some(sum));
         }
 
-        Ok(solution)
+        Ok(max_happiness)
     }
 
     fn calculate_pair_happiness(
@@ -104,29 +106,10 @@ impl SeatingArrangement {
     fn get_relation(&self, from_idx: PersonIdx, to_idx: PersonIdx) -> anyhow::Result<i32> {
         self.relations
             .get(&(from_idx, to_idx))
-            .ok_or_else(|| anyhow!("could not find relation for {from_idx}: {to_idx}"))
             .copied()
-    }
-}
-
-#[derive(Default)]
-pub struct Solution {
-    pub max_happiness: Option<i32>,
-    pub max_happiness_with_add: Option<i32>,
-}
-
-impl Solution {
-    fn solve_max_happiness(&mut self, happiness: i32) {
-        self.max_happiness = match self.max_happiness {
-            Some(current_max) => Some(max(current_max, happiness)),
-            None => Some(happiness),
-        };
-    }
-
-    fn solve_max_happiness_with_add(&mut self, happiness: i32) {
-        self.max_happiness_with_add = match self.max_happiness_with_add {
-            Some(current_max) => Some(max(current_max, happiness)),
-            None => Some(happiness),
-        };
+            .ok_or_else(|| {
+                let message = format!("could not find relation for {from_idx}: {to_idx}");
+                Error::unsolvable(message).into()
+            })
     }
 }
@@ -1,5 +1,5 @@
+use crate::error::{Error, parse_capture};
 use regex::Regex;
-use std::cmp::max;
 
 pub struct Parser {
     re: Regex,
@@ -14,14 +14,14 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Reindeer> {
+    pub fn parse(&self, input: &str) -> Result<Reindeer, Error> {
         let Some(caps) = self.re.captures(input) else {
-            anyhow::bail!("could not parse reindeer data");
+            return Err(Error::parse(1, "could not parse reindeer data"));
         };
 
-        let speed = caps["speed"].parse()?;
-        let fly_time = caps["fly_time"].parse()?;
-        let rest_time = caps["rest_time"].parse()?;
+        let speed = parse_capture(&caps, "speed")?;
+        let fly_time = parse_capture(&caps, "fly_time")?;
+        let rest_time = parse_capture(&caps, "rest_time")?;
 
         let result = Reindeer {
             speed,
@@ -40,8 +40,17 @@ pub struct Reindeer {
 }
 
 impl Reindeer {
-    fn get_cycle_time(&self) -> u32 {
-        self.fly_time + self.rest_time
+    fn distance_after(&self, time: u32) -> u32 {
+        let cycle_time = self.fly_time.saturating_add(self.rest_time);
+
+        if cycle_time == 0 {
+            return 0;
+        }
+
+        let flying_time =
+            time / cycle_time * self.fly_time + (time % cycle_time).min(self.fly_time);
+
+        flying_time.saturating_mul(self.speed)
     }
 }
 
@@ -55,46 +64,34 @@ impl Race {
         self.reindeer.push(data);
     }
 
-    pub fn run(&mut self, time: u32) -> anyhow::Result<RunResult> {
-        let mut distances = vec![0u32; self.reindeer.len()];
+    pub fn winning_distance(&self, time: u32) -> Option<u32> {
+        self.reindeer
+            .iter()
+            .map(|reindeer| reindeer.distance_after(time))
+            .max()
+    }
+
+    pub fn winning_points(&self, time: u32) -> Option<u32> {
         let mut points = vec![0u32; self.reindeer.len()];
 
-        for current_second in 0..time {
-            for (idx, reindeer) in self.reindeer.iter().enumerate() {
-                if current_second % reindeer.get_cycle_time() < reindeer.fly_time {
-                    distances[idx] += reindeer.speed;
-                }
-            }
+        for current_second in 1..=time {
+            let distances = self
+                .reindeer
+                .iter()
+                .map(|reindeer| reindeer.distance_after(current_second))
+                .collect::<Vec<_>>();
 
-            let mut current_max = None;
-
-            for distance in &distances {
-                current_max = match current_max {
-                    Some(max_dist) => Some(max(max_dist, distance)),
-                    None => Some(distance),
-                }
-            }
+            let Some(&leading_distance) = distances.iter().max() else {
+                break;
+            };
 
             for (idx, distance) in distances.iter().enumerate() {
-                if distance == current_max.unwrap() {
+                if *distance == leading_distance {
                     points[idx] += 1;
                 }
             }
         }
 
-        let winning_distance = distances.iter().max().cloned();
-        let winning_points = points.iter().max().cloned();
-
-        let result = RunResult {
-            winning_distance,
-            winning_points,
-        };
-
-        Ok(result)
+        points.into_iter().max()
     }
 }
-
-pub struct RunResult {
-    pub winning_distance: Option<u32>,
-    pub winning_points: Option<u32>,
-}
@@ -1,52 +1,46 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Parser, Race};
+use indoc::indoc;
 use show_option::ShowOption;
 
-mod helpers;
+pub mod helpers;
+
+const RACE_TIME: u32 = 2503;
+
+const INPUT: &str = indoc! {
+    "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+    Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds."
+};
 
 puzzle_solver!(
     [2015, 14] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Reindeer Olympics",
+        parts: ["Winning distance", "Winning points"],
+        examples: [
+            (INPUT, ["2660", "1564"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
             let mut race = Race::default();
 
-            for line in input.lines() {
-                let reindeer = parser.parse(line)?;
+            for (idx, line) in input.lines().enumerate() {
+                let reindeer = parser.parse(line).at_line(idx + 1)?;
                 race.add_reindeer(reindeer);
             }
 
-            let run_result = race.run(2503)?;
+            session.parsed();
 
-            let winning_distance = run_result.winning_distance.show_or("none").to_string();
-            let winning_points = run_result.winning_points.show_or("none").to_string();
+            let winning_distance = session.solve_part(|| {
+                anyhow::Ok(race.winning_distance(RACE_TIME).show_or("none").to_string())
+            })?;
+
+            let winning_points = session.solve_part(|| {
+                anyhow::Ok(race.winning_points(RACE_TIME).show_or("none").to_string())
+            })?;
 
             answer!(winning_distance, winning_points);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::puzzle::Solver;
-    use indoc::indoc;
-    use rstest::rstest;
-
-    const INPUT: &str = indoc! {
-        "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
-        Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds."
-    };
-
-    #[rstest]
-    #[case(INPUT, 2660, 1564)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_max_distance: u32,
-        #[case] expected_winning_points: u32,
-    ) {
-        let solution = Puzzle.solve(input).unwrap();
-
-        assert_eq!(solution.results[0], expected_max_distance.to_string());
-        assert_eq!(solution.results[1], expected_winning_points.to_string());
-    }
-}
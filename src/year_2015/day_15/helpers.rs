@@ -1,3 +1,4 @@
+use crate::error::{Error, parse_capture};
 use regex::Regex;
 use std::cmp::max;
 
@@ -14,16 +15,17 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Ingredient> {
+    pub fn parse(&self, input: &str) -> Result<Ingredient, Error> {
         let Some(caps) = self.re.captures(input) else {
-            anyhow::bail!("could not parse input '{input}' as an ingredient");
+            let message = format!("could not parse input '{input}' as an ingredient");
+            return Err(Error::parse(1, message));
         };
 
-        let capacity = caps["capacity"].parse()?;
-        let durability = caps["durability"].parse()?;
-        let flavor = caps["flavor"].parse()?;
-        let texture = caps["texture"].parse()?;
-        let calories = caps["calories"].parse()?;
+        let capacity = parse_capture(&caps, "capacity")?;
+        let durability = parse_capture(&caps, "durability")?;
+        let flavor = parse_capture(&caps, "flavor")?;
+        let texture = parse_capture(&caps, "texture")?;
+        let calories = parse_capture(&caps, "calories")?;
 
         let result = Ingredient {
             capacity,
@@ -56,6 +58,10 @@ impl RecipeCalculator {
     }
 
     pub fn solve_recipe(&mut self, spoon_amount: u32) -> anyhow::Result<Solution> {
+        if self.ingredients.is_empty() {
+            return Err(Error::unsolvable("no ingredients given").into());
+        }
+
         let mut amounts = vec![0; self.ingredients.len()];
         let mut solution = Solution::default();
 
@@ -1,3 +1,4 @@
+use crate::error::{Error, ParseContext, parse_capture};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -16,18 +17,19 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Aunt> {
+    pub fn parse(&self, input: &str) -> Result<Aunt, Error> {
         let Some(caps) = self.re_index.captures(input) else {
-            anyhow::bail!("could not parse index");
+            return Err(Error::parse(1, "could not parse index"));
         };
 
-        let index = caps["index"].parse()?;
+        let index = parse_capture(&caps, "index")?;
 
         let mut params = HashMap::new();
 
         for caps in self.re_param.captures_iter(input) {
-            let param = AuntParam::from(&caps["param"])?;
-            let amount = caps["amount"].parse()?;
+            let column = caps.name("param").map_or(0, |m| m.start()) + 1;
+            let param = AuntParam::from(&caps["param"]).at_column(column)?;
+            let amount = parse_capture(&caps, "amount")?;
 
             params.insert(param, amount);
         }
@@ -51,7 +53,7 @@ pub enum AuntParam {
 }
 
 impl AuntParam {
-    pub fn from(name: &str) -> anyhow::Result<Self> {
+    pub fn from(name: &str) -> Result<Self, Error> {
         let result = match name {
             "children" => Self::Children,
             "cats" => Self::Cats,
@@ -63,7 +65,7 @@ impl AuntParam {
             "trees" => Self::Trees,
             "cars" => Self::Cars,
             "perfumes" => Self::Perfumes,
-            _ => anyhow::bail!("unknown parameter: {name}"),
+            _ => return Err(Error::parse(1, format!("unknown parameter: {name}"))),
         };
 
         Ok(result)
@@ -117,29 +119,17 @@ impl Analyser {
         self.aunts.push(info);
     }
 
-    pub fn solve_aunt_indices(&self, aunt_to_find: &AuntParams) -> anyhow::Result<Solution> {
-        let mut index_exact = None;
-        let mut index_approx = None;
-
-        for aunt in &self.aunts {
-            if aunt.equals_exact(aunt_to_find) {
-                index_exact = Some(aunt.index);
-            }
-            if aunt.equals_approx(aunt_to_find) {
-                index_approx = Some(aunt.index);
-            }
-        }
-
-        let solution = Solution {
-            index_exact,
-            index_approx,
-        };
-
-        Ok(solution)
+    pub fn find_exact(&self, aunt_to_find: &AuntParams) -> Option<usize> {
+        self.aunts
+            .iter()
+            .rfind(|aunt| aunt.equals_exact(aunt_to_find))
+            .map(|aunt| aunt.index)
     }
-}
 
-pub struct Solution {
-    pub index_exact: Option<usize>,
-    pub index_approx: Option<usize>,
+    pub fn find_approx(&self, aunt_to_find: &AuntParams) -> Option<usize> {
+        self.aunts
+            .iter()
+            .rfind(|aunt| aunt.equals_approx(aunt_to_find))
+            .map(|aunt| aunt.index)
+    }
 }
@@ -1,21 +1,27 @@
+use crate::error::ParseContext;
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{Analyser, AuntParam, Parser};
 use show_option::ShowOption;
 use std::collections::HashMap;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 16] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Aunt Sue",
+        parts: ["Aunt matching exact readings", "Aunt matching ranged readings"],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
             let mut analyser = Analyser::default();
 
-            for line in input.lines() {
-                let aunt = parser.parse(line)?;
+            for (idx, line) in input.lines().enumerate() {
+                let aunt = parser.parse(line).at_line(idx + 1)?;
                 analyser.add_aunt(aunt);
             }
 
+            session.parsed();
+
             let aunt_to_find = HashMap::from([
                 (AuntParam::Children, 3),
                 (AuntParam::Cats, 7),
@@ -29,10 +35,13 @@ puzzle_solver!(
                 (AuntParam::Perfumes, 1),
             ]);
 
-            let solution = analyser.solve_aunt_indices(&aunt_to_find)?;
+            let index_exact = session.solve_part(|| {
+                anyhow::Ok(analyser.find_exact(&aunt_to_find).show_or("none").to_string())
+            })?;
 
-            let index_exact = solution.index_exact.show_or("none").to_string();
-            let index_approx = solution.index_approx.show_or("none").to_string();
+            let index_approx = session.solve_part(|| {
+                anyhow::Ok(analyser.find_approx(&aunt_to_find).show_or("none").to_string())
+            })?;
 
             answer!(index_exact, index_approx);
         }
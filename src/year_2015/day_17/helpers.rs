@@ -28,7 +28,9 @@ impl CombinationCounter {
     pub fn count(&self, amount: u32) -> Solution {
         let mut solution = Solution::default();
 
-        self.check_combinations(&mut solution, 0, 0, amount);
+        if !self.containers.sizes.is_empty() {
+            self.check_combinations(&mut solution, 0, 0, amount);
+        }
 
         solution
     }
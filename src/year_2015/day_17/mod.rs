@@ -1,33 +1,39 @@
+use crate::error::Error;
 use crate::puzzle::{answer, puzzle_solver};
-use anyhow::Context;
 use helpers::{CombinationCounter, Containers};
 use show_option::ShowOption;
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 17] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "No Such Thing as Too Much",
+        parts: ["Container combinations", "Minimal container combinations"],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let mut containers = Containers::default();
 
-            for line in input.lines() {
-                let size = line
-                    .parse()
-                    .with_context(|| format!("invalid container size: {line}"))?;
+            for (idx, line) in input.lines().enumerate() {
+                let size = line.parse().map_err(|_| {
+                    Error::parse(1, format!("invalid container size: {line}")).at_line(idx + 1)
+                })?;
                 containers.add(size);
             }
 
             containers.sort_desc();
 
+            session.parsed();
+
             let counter = CombinationCounter::new(containers);
-            let solution = counter.count(150);
 
-            let combination_count = solution.get_combination_count().show_or("none").to_string();
+            let [combination_count, minimal_combination_count] = session.solve_parts(|| {
+                let solution = counter.count(150);
 
-            let minimal_combination_count = solution
-                .get_minimal_combination_count()
-                .show_or("none")
-                .to_string();
+                Ok([
+                    solution.get_combination_count().show_or("none").to_string(),
+                    solution.get_minimal_combination_count().show_or("none").to_string(),
+                ])
+            })?;
 
             answer!(combination_count, minimal_combination_count);
         }
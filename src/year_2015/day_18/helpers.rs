@@ -27,7 +27,7 @@ impl LightArray {
         let mut array = vec![vec![LightState::Off; height]; width];
 
         for (x, y) in lights_stuck_on.iter() {
-            array[*y][*x] = LightState::On;
+            array[*x][*y] = LightState::On;
         }
 
         Self {
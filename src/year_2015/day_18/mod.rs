@@ -1,25 +1,32 @@
+use crate::error::Error;
 use crate::puzzle::{answer, puzzle_solver};
-use anyhow::anyhow;
 use helpers::{LightArray, LightState};
 
-mod helpers;
+pub mod helpers;
 
 puzzle_solver!(
     [2015, 18] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Like a GIF For Your Yard",
+        parts: ["Lights on after 100 steps", "Lights on with stuck corners"],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let height = input.lines().count();
             let width = input
                 .lines()
                 .next()
-                .ok_or_else(|| anyhow!("Empty input"))?
+                .ok_or_else(|| Error::parse(1, "Empty input"))?
                 .chars()
                 .count();
 
-            let mut lights = LightArray::new(height, width);
+            if width == 0 {
+                return Err(Error::parse(1, "Empty row").into());
+            }
+
+            let mut lights = LightArray::new(width, height);
 
             let mut lights_stuck = LightArray::with_lights_stuck_on(
-                height,
                 width,
+                height,
                 &[
                     (0, 0),
                     (0, height - 1),
@@ -29,22 +36,36 @@ puzzle_solver!(
             );
 
             for (y, line) in input.lines().enumerate() {
+                if line.chars().count() != width {
+                    let message = format!("expected {width} lights in row: '{line}'");
+                    return Err(Error::parse(1, message).at_line(y + 1).into());
+                }
+
                 for (x, c) in line.chars().enumerate() {
                     let state = match c {
                         '#' => LightState::On,
                         '.' => LightState::Off,
-                        other => anyhow::bail!("unknown character {}", other),
+                        other => {
+                            let message = format!("unknown character {other}");
+                            return Err(Error::parse(x + 1, message).at_line(y + 1).into());
+                        }
                     };
                     lights.set(x, y, state);
                     lights_stuck.set(x, y, state);
                 }
             }
 
-            lights.animate(100);
-            lights_stuck.animate(100);
+            session.parsed();
+
+            let standard_count = session.solve_part(|| {
+                lights.animate(100);
+                Ok(lights.count_lit())
+            })?;
 
-            let standard_count = lights.count_lit();
-            let fixed_corners_count = lights_stuck.count_lit();
+            let fixed_corners_count = session.solve_part(|| {
+                lights_stuck.animate(100);
+                Ok(lights_stuck.count_lit())
+            })?;
 
             answer!(standard_count, fixed_corners_count);
         }
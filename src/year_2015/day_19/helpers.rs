@@ -1,3 +1,5 @@
+use crate::error::Error;
+use crate::puzzle::Cancellation;
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use regex::Regex;
@@ -23,14 +25,17 @@ impl Default for Parser {
 }
 
 impl Parser {
-    pub fn parse(&self, input: &str) -> anyhow::Result<Replacement> {
+    pub fn parse(&self, input: &str) -> Result<Replacement, Error> {
         if let Some(caps) = self.re.captures(input) {
             let left = caps["left"].to_string();
             let right = caps["right"].to_string();
 
             Ok(Replacement { left, right })
         } else {
-            anyhow::bail!("invalid input for replacement `{}`", input);
+            Err(Error::parse(
+                1,
+                format!("invalid input for replacement `{input}`"),
+            ))
         }
     }
 }
@@ -76,7 +81,10 @@ impl MoleculeReplacer {
         Ok(molecules.len())
     }
 
-    pub fn count_synthesis_steps(&self) -> anyhow::Result<Option<u32>> {
+    pub fn count_synthesis_steps(
+        &self,
+        cancellation: &Cancellation,
+    ) -> anyhow::Result<Option<u32>> {
         let mut replacements: Vec<&Replacement> = self.replacements.iter().collect_vec();
 
         let mut cache = HashMap::new();
@@ -89,6 +97,8 @@ impl MoleculeReplacer {
         let mut rand = rand::rng();
 
         let min_steps = loop {
+            cancellation.check()?;
+
             let mut breaker = 0;
 
             replacements.shuffle(&mut rand);
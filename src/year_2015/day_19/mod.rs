@@ -1,18 +1,36 @@
+use crate::error::{Error, ParseContext};
 use crate::puzzle::{answer, puzzle_solver};
 use helpers::{MoleculeReplacer, Parser, ParsingMode};
+use indoc::indoc;
 use show_option::ShowOption;
 
-mod helpers;
+pub mod helpers;
+
+const INPUT: &str = indoc! {
+    "e => H
+    e => O
+    H => HO
+    H => OH
+    O => HH
+
+    HOHOHO"
+};
 
 puzzle_solver!(
     [2015, 19] = {
-        fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        title: "Medicine for Rudolph",
+        parts: ["Distinct molecules", "Steps to make the medicine"],
+        examples: [
+            (INPUT, ["7", "6"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
             let parser = Parser::default();
             let mut parsing_mode = ParsingMode::Replacements;
 
             let mut molecule_replacer = MoleculeReplacer::default();
 
-            for line in input.lines() {
+            for (idx, line) in input.lines().enumerate() {
                 if line.is_empty() {
                     parsing_mode = ParsingMode::Molecule;
                     continue;
@@ -20,59 +38,32 @@ puzzle_solver!(
 
                 match parsing_mode {
                     ParsingMode::Replacements => {
-                        let replacement = parser.parse(line)?;
+                        let replacement = parser.parse(line).at_line(idx + 1)?;
                         molecule_replacer.add_replacement(replacement);
                     }
                     ParsingMode::Molecule => {
                         molecule_replacer.set_molecule(line);
                         parsing_mode = ParsingMode::Finished;
                     }
-                    ParsingMode::Finished => anyhow::bail!("unexpected input: '{line}'"),
+                    ParsingMode::Finished => {
+                        let message = format!("unexpected input: '{line}'");
+                        return Err(Error::parse(1, message).at_line(idx + 1).into());
+                    }
                 }
             }
 
-            let molecule_count = molecule_replacer.get_molecules_count()?;
+            session.parsed();
+
+            let molecule_count = session.solve_part(|| molecule_replacer.get_molecules_count())?;
+
+            let cancellation = session.cancellation();
 
-            let min_synthesis_steps = molecule_replacer
-                .count_synthesis_steps()?
-                .show_or("none")
-                .to_string();
+            let min_synthesis_steps = session.solve_part(|| {
+                let steps = molecule_replacer.count_synthesis_steps(&cancellation)?;
+                Ok(steps.show_or("none").to_string())
+            })?;
 
             answer!(molecule_count, min_synthesis_steps);
         }
     }
 );
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::puzzle::Solver;
-    use indoc::indoc;
-    use rstest::rstest;
-
-    const INPUT: &str = indoc! {
-        "e => H
-        e => O
-        H => HO
-        H => OH
-        O => HH
-
-        HOHOHO"
-    };
-
-    #[rstest]
-    #[case(INPUT, 7, 6)]
-    fn positive_tests(
-        #[case] input: &str,
-        #[case] expected_molecule_count: u32,
-        #[case] expected_min_synthesis_steps: u32,
-    ) {
-        let solution = Puzzle.solve(input).unwrap();
-
-        assert_eq!(solution.results[0], expected_molecule_count.to_string());
-        assert_eq!(
-            solution.results[1],
-            expected_min_synthesis_steps.to_string()
-        );
-    }
-}
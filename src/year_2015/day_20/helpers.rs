@@ -0,0 +1,63 @@
+use crate::puzzle::Cancellation;
+use std::cmp::min;
+
+const BLOCK_SIZE: u64 = 1 << 16;
+
+/// Sums the presents of consecutive blocks of houses, so that memory stays
+/// bounded and the search stops at the first block reaching the target.
+pub struct PresentSieve {
+    presents_per_elf: u64,
+    visit_limit: Option<u64>,
+}
+
+impl PresentSieve {
+    pub fn new(presents_per_elf: u64, visit_limit: Option<u64>) -> Self {
+        Self {
+            presents_per_elf,
+            visit_limit,
+        }
+    }
+
+    pub fn first_house(&self, target: u64, cancellation: &Cancellation) -> anyhow::Result<u64> {
+        // Elf `n` alone brings enough presents to house `n` once `n` reaches this bound.
+        let last_house = target.div_ceil(self.presents_per_elf).max(1);
+
+        let mut start = 1;
+
+        loop {
+            cancellation.check()?;
+
+            let end = min(start + BLOCK_SIZE, last_house + 1);
+            let presents = self.deliver(start, end);
+
+            if let Some(offset) = presents.iter().position(|&count| count >= target) {
+                return Ok(start + offset as u64);
+            }
+
+            start = end;
+        }
+    }
+
+    fn deliver(&self, start: u64, end: u64) -> Vec<u64> {
+        let mut presents = vec![0; (end - start) as usize];
+
+        let first_elf = match self.visit_limit {
+            Some(limit) => start.div_ceil(limit).max(1),
+            None => 1,
+        };
+
+        for elf in first_elf..end {
+            let last_visit = self
+                .visit_limit
+                .map_or(end - 1, |limit| min(elf.saturating_mul(limit), end - 1));
+            let mut house = start.div_ceil(elf) * elf;
+
+            while house <= last_visit {
+                presents[(house - start) as usize] += elf * self.presents_per_elf;
+                house += elf;
+            }
+        }
+
+        presents
+    }
+}
@@ -0,0 +1,59 @@
+use crate::error::Error;
+use crate::puzzle::{answer, puzzle_solver};
+use helpers::PresentSieve;
+
+pub mod helpers;
+
+const PRESENTS_PER_ELF: u64 = 10;
+const LAZY_PRESENTS_PER_ELF: u64 = 11;
+const LAZY_VISIT_LIMIT: u64 = 50;
+
+puzzle_solver!(
+    [2015, 20] = {
+        title: "Infinite Elves and Infinite Houses",
+        parts: ["Lowest house number", "Lowest house number with lazy elves"],
+        examples: [
+            ("10", ["1", "1"]),
+            ("70", ["4", "4"]),
+            ("150", ["8", "8"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let target: u64 = input
+                .parse()
+                .map_err(|_| Error::parse(1, format!("invalid present count: {input}")))?;
+
+            session.parsed();
+
+            let cancellation = session.cancellation();
+
+            let lowest_house = session.solve_part(|| {
+                PresentSieve::new(PRESENTS_PER_ELF, None).first_house(target, &cancellation)
+            })?;
+
+            let lowest_lazy_house = session.solve_part(|| {
+                PresentSieve::new(LAZY_PRESENTS_PER_ELF, Some(LAZY_VISIT_LIMIT))
+                    .first_house(target, &cancellation)
+            })?;
+
+            answer!(lowest_house, lowest_lazy_house);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::Solver;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("")]
+    #[case("-10")]
+    #[case("1O0")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
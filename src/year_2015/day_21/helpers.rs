@@ -0,0 +1,175 @@
+use crate::error::{Error, ParseContext, parse_capture};
+use itertools::Itertools;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+
+pub struct Parser {
+    re: Regex,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            re: Regex::new(r"^(?<stat>Hit Points|Damage|Armor): (?<value>\d+)$").unwrap(),
+        }
+    }
+}
+
+impl Parser {
+    /// Parses the boss statistics; a missing armor line counts as no armor.
+    pub fn parse(&self, input: &str) -> Result<Stats, Error> {
+        let mut hit_points = None;
+        let mut damage = None;
+        let mut armor = None;
+
+        for (idx, line) in input.lines().enumerate() {
+            let Some(caps) = self.re.captures(line) else {
+                let message = format!("could not parse boss statistic: '{line}'");
+                return Err(Error::parse(1, message).at_line(idx + 1));
+            };
+
+            let stat = match &caps["stat"] {
+                "Hit Points" => &mut hit_points,
+                "Damage" => &mut damage,
+                _ => &mut armor,
+            };
+
+            if stat.is_some() {
+                let message = format!("duplicate statistic: '{}'", &caps["stat"]);
+                return Err(Error::parse(1, message).at_line(idx + 1));
+            }
+
+            *stat = Some(parse_capture(&caps, "value").at_line(idx + 1)?);
+        }
+
+        let Some(hit_points) = hit_points else {
+            return Err(Error::parse(1, "missing 'Hit Points'"));
+        };
+
+        let Some(damage) = damage else {
+            return Err(Error::parse(1, "missing 'Damage'"));
+        };
+
+        Ok(Stats {
+            hit_points,
+            damage,
+            armor: armor.unwrap_or(0),
+        })
+    }
+}
+
+/// The combat model: fighters take turns, every attack deals its damage
+/// reduced by the defender's armor, but always at least 1 point.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl Stats {
+    pub fn damage_against(&self, defender: &Stats) -> u32 {
+        self.damage.saturating_sub(defender.armor).max(1)
+    }
+
+    pub fn turns_to_defeat(&self, defender: &Stats) -> u32 {
+        defender.hit_points.div_ceil(self.damage_against(defender))
+    }
+
+    /// Decides the fight without simulating it; the attacker strikes first.
+    pub fn defeats(&self, defender: &Stats) -> bool {
+        self.turns_to_defeat(defender) <= defender.turns_to_defeat(self)
+    }
+}
+
+pub struct Item {
+    pub name: &'static str,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+const fn item(name: &'static str, cost: u32, damage: u32, armor: u32) -> Item {
+    Item {
+        name,
+        cost,
+        damage,
+        armor,
+    }
+}
+
+const WEAPONS: &[Item] = &[
+    item("Dagger", 8, 4, 0),
+    item("Shortsword", 10, 5, 0),
+    item("Warhammer", 25, 6, 0),
+    item("Longsword", 40, 7, 0),
+    item("Greataxe", 74, 8, 0),
+];
+
+const ARMOR: &[Item] = &[
+    item("Leather", 13, 0, 1),
+    item("Chainmail", 31, 0, 2),
+    item("Splintmail", 53, 0, 3),
+    item("Bandedmail", 75, 0, 4),
+    item("Platemail", 102, 0, 5),
+];
+
+const RINGS: &[Item] = &[
+    item("Damage +1", 25, 1, 0),
+    item("Damage +2", 50, 2, 0),
+    item("Damage +3", 100, 3, 0),
+    item("Defense +1", 20, 0, 1),
+    item("Defense +2", 40, 0, 2),
+    item("Defense +3", 80, 0, 3),
+];
+
+pub struct Loadout {
+    pub items: Vec<&'static Item>,
+}
+
+impl Loadout {
+    pub fn cost(&self) -> u32 {
+        self.items.iter().map(|item| item.cost).sum()
+    }
+
+    pub fn equip(&self, hit_points: u32) -> Stats {
+        Stats {
+            hit_points,
+            damage: self.items.iter().map(|item| item.damage).sum(),
+            armor: self.items.iter().map(|item| item.armor).sum(),
+        }
+    }
+}
+
+impl Display for Loadout {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let names = self.items.iter().map(|item| item.name).join(", ");
+
+        write!(f, "{names} ({} gold)", self.cost())
+    }
+}
+
+pub struct Shop;
+
+impl Shop {
+    /// Every loadout of exactly one weapon, at most one armor and at most two rings.
+    pub fn loadouts() -> impl Iterator<Item = Loadout> {
+        let armor = Self::up_to(ARMOR, 1);
+        let rings = Self::up_to(RINGS, 2);
+
+        WEAPONS
+            .iter()
+            .cartesian_product(armor)
+            .cartesian_product(rings)
+            .map(|((weapon, armor), rings)| {
+                let items = [weapon].into_iter().chain(armor).chain(rings).collect();
+                Loadout { items }
+            })
+    }
+
+    fn up_to(items: &'static [Item], count: usize) -> Vec<Vec<&'static Item>> {
+        (0..=count)
+            .flat_map(|count| items.iter().combinations(count))
+            .collect()
+    }
+}
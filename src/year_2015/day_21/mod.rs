@@ -0,0 +1,137 @@
+use crate::puzzle::{answer, puzzle_solver};
+use helpers::{Loadout, Parser, Shop};
+use indoc::indoc;
+use show_option::ShowOption;
+
+pub mod helpers;
+
+const PLAYER_HIT_POINTS: u32 = 100;
+
+const INPUT_ARMORED: &str = indoc! {
+    "Hit Points: 104
+    Damage: 8
+    Armor: 1"
+};
+
+const INPUT_HEAVILY_ARMORED: &str = indoc! {
+    "Hit Points: 100
+    Damage: 8
+    Armor: 2"
+};
+
+const INPUT_WEAK_BOSS: &str = indoc! {
+    "Hit Points: 12
+    Damage: 7"
+};
+
+puzzle_solver!(
+    [2015, 21] = {
+        title: "RPG Simulator 20XX",
+        parts: ["Least gold to win", "Most gold to lose"],
+        examples: [
+            (INPUT_ARMORED, ["78", "148"]),
+            (INPUT_HEAVILY_ARMORED, ["91", "158"]),
+            (INPUT_WEAK_BOSS, ["8", "none"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let boss = Parser::default().parse(input)?;
+
+            session.parsed();
+
+            let wins = |loadout: &Loadout| loadout.equip(PLAYER_HIT_POINTS).defeats(&boss);
+            let mut loadouts: Vec<(usize, Loadout)> = Vec::new();
+
+            let least_gold_to_win = session.solve_part(|| {
+                let loadout = Shop::loadouts().filter(wins).min_by_key(Loadout::cost);
+                let cost = loadout.as_ref().map(Loadout::cost);
+
+                loadouts.extend(loadout.map(|loadout| (1, loadout)));
+
+                anyhow::Ok(cost.show_or("none").to_string())
+            })?;
+
+            let most_gold_to_lose = session.solve_part(|| {
+                let loadout = Shop::loadouts()
+                    .filter(|loadout| !wins(loadout))
+                    .max_by_key(Loadout::cost);
+                let cost = loadout.as_ref().map(Loadout::cost);
+
+                loadouts.extend(loadout.map(|loadout| (2, loadout)));
+
+                anyhow::Ok(cost.show_or("none").to_string())
+            })?;
+
+            for (part, loadout) in loadouts {
+                session.trace(format!("Part {part}: {loadout}"));
+            }
+
+            answer!(least_gold_to_win, most_gold_to_lose);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Session, Solver};
+    use helpers::Stats;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(8, 5, 5, 12, 7, 2, true)]
+    #[case(8, 5, 5, 13, 7, 2, false)]
+    #[case(1, 0, 0, 1, 100, 100, true)]
+    fn combat_tests(
+        #[case] player_hit_points: u32,
+        #[case] player_damage: u32,
+        #[case] player_armor: u32,
+        #[case] boss_hit_points: u32,
+        #[case] boss_damage: u32,
+        #[case] boss_armor: u32,
+        #[case] expected_win: bool,
+    ) {
+        let player = Stats {
+            hit_points: player_hit_points,
+            damage: player_damage,
+            armor: player_armor,
+        };
+
+        let boss = Stats {
+            hit_points: boss_hit_points,
+            damage: boss_damage,
+            armor: boss_armor,
+        };
+
+        assert_eq!(player.defeats(&boss), expected_win);
+    }
+
+    #[rstest]
+    #[case(
+        INPUT_ARMORED,
+        &["Part 1: Longsword, Leather, Damage +1 (78 gold)", "Part 2: Dagger, Damage +3, Defense +2 (148 gold)"]
+    )]
+    #[case(
+        INPUT_HEAVILY_ARMORED,
+        &["Part 1: Longsword, Chainmail, Defense +1 (91 gold)", "Part 2: Dagger, Damage +2, Damage +3 (158 gold)"]
+    )]
+    #[case(INPUT_WEAK_BOSS, &["Part 1: Dagger (8 gold)"])]
+    fn trace_tests(#[case] input: &str, #[case] expected_trace: &[&str]) {
+        let answer = Puzzle
+            .solve_in(input, Session::start(None).with_trace(true))
+            .unwrap();
+
+        assert_eq!(answer.trace, expected_trace);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Hit Points: 100")]
+    #[case("Hit Points: 100\nDamage: 8\nDamage: 8")]
+    #[case("Hit Points: 100\nDamage: 8\nMagic: 2")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
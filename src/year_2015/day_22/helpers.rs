@@ -0,0 +1,295 @@
+use crate::puzzle::Cancellation;
+use crate::year_2015::day_21::helpers::Stats;
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::{Display, Formatter};
+
+const SHIELD_ARMOR: u32 = 7;
+const CANCELLATION_CHECK_INTERVAL: usize = 0x1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const ALL: [Self; 5] = [
+        Self::MagicMissile,
+        Self::Drain,
+        Self::Shield,
+        Self::Poison,
+        Self::Recharge,
+    ];
+
+    pub fn cost(self) -> u32 {
+        match self {
+            Self::MagicMissile => 53,
+            Self::Drain => 73,
+            Self::Shield => 113,
+            Self::Poison => 173,
+            Self::Recharge => 229,
+        }
+    }
+}
+
+impl Display for Spell {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            Self::MagicMissile => "Magic Missile",
+            Self::Drain => "Drain",
+            Self::Shield => "Shield",
+            Self::Poison => "Poison",
+            Self::Recharge => "Recharge",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Normal,
+    Hard,
+}
+
+/// Turns left for each of the timed effects.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct Effects {
+    shield: u8,
+    poison: u8,
+    recharge: u8,
+}
+
+/// The game state at the start of a player turn.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Battle {
+    hit_points: u32,
+    mana: u32,
+    boss_hit_points: u32,
+    effects: Effects,
+}
+
+enum Outcome {
+    Won,
+    Lost,
+    Ongoing(Battle),
+}
+
+impl Battle {
+    pub fn new(hit_points: u32, mana: u32, boss: &Stats) -> Self {
+        Self {
+            hit_points,
+            mana,
+            boss_hit_points: boss.hit_points,
+            effects: Effects::default(),
+        }
+    }
+
+    /// Applies the active effects and returns the player's armor for the turn.
+    fn apply_effects(&mut self) -> u32 {
+        let effects = &mut self.effects;
+        let mut armor = 0;
+
+        if effects.shield > 0 {
+            effects.shield -= 1;
+            armor = SHIELD_ARMOR;
+        }
+
+        if effects.poison > 0 {
+            effects.poison -= 1;
+            self.boss_hit_points = self.boss_hit_points.saturating_sub(3);
+        }
+
+        if effects.recharge > 0 {
+            effects.recharge -= 1;
+            self.mana += 101;
+        }
+
+        armor
+    }
+
+    fn can_cast(&self, spell: Spell) -> bool {
+        let inactive = match spell {
+            Spell::Shield => self.effects.shield == 0,
+            Spell::Poison => self.effects.poison == 0,
+            Spell::Recharge => self.effects.recharge == 0,
+            Spell::MagicMissile | Spell::Drain => true,
+        };
+
+        inactive && self.mana >= spell.cost()
+    }
+
+    fn cast(&mut self, spell: Spell) {
+        self.mana -= spell.cost();
+
+        match spell {
+            Spell::MagicMissile => self.boss_hit_points = self.boss_hit_points.saturating_sub(4),
+            Spell::Drain => {
+                self.boss_hit_points = self.boss_hit_points.saturating_sub(2);
+                self.hit_points += 2;
+            }
+            Spell::Shield => self.effects.shield = 6,
+            Spell::Poison => self.effects.poison = 6,
+            Spell::Recharge => self.effects.recharge = 5,
+        }
+    }
+
+    /// Plays the start of the player turn, before a spell is cast.
+    fn begin_turn(mut self, difficulty: Difficulty) -> Outcome {
+        if difficulty == Difficulty::Hard {
+            self.hit_points = self.hit_points.saturating_sub(1);
+
+            if self.hit_points == 0 {
+                return Outcome::Lost;
+            }
+        }
+
+        self.apply_effects();
+
+        match self.boss_hit_points {
+            0 => Outcome::Won,
+            _ => Outcome::Ongoing(self),
+        }
+    }
+
+    /// Casts a spell and plays the boss turn that follows.
+    fn play(mut self, spell: Spell, boss: &Stats) -> Outcome {
+        self.cast(spell);
+
+        if self.boss_hit_points == 0 {
+            return Outcome::Won;
+        }
+
+        let armor = self.apply_effects();
+
+        if self.boss_hit_points == 0 {
+            return Outcome::Won;
+        }
+
+        let player = Stats {
+            hit_points: self.hit_points,
+            damage: 0,
+            armor,
+        };
+
+        self.hit_points = self.hit_points.saturating_sub(boss.damage_against(&player));
+
+        match self.hit_points {
+            0 => Outcome::Lost,
+            _ => Outcome::Ongoing(self),
+        }
+    }
+}
+
+pub struct Victory {
+    pub mana_spent: u32,
+    pub spells: Vec<Spell>,
+}
+
+impl Display for Victory {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({} mana)",
+            self.spells.iter().join(" -> "),
+            self.mana_spent
+        )
+    }
+}
+
+struct Node {
+    battle: Battle,
+    won: bool,
+    parent: Option<(usize, Spell)>,
+}
+
+pub struct SpellSearch<'a> {
+    boss: &'a Stats,
+    difficulty: Difficulty,
+}
+
+impl<'a> SpellSearch<'a> {
+    pub fn new(boss: &'a Stats, difficulty: Difficulty) -> Self {
+        Self { boss, difficulty }
+    }
+
+    /// Finds the cheapest spell sequence that wins, ordering states by mana spent.
+    pub fn cheapest_win(
+        &self,
+        battle: Battle,
+        cancellation: &Cancellation,
+    ) -> anyhow::Result<Option<Victory>> {
+        let mut nodes = vec![Node {
+            battle,
+            won: false,
+            parent: None,
+        }];
+
+        let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+        let mut visited = HashSet::new();
+
+        while let Some(Reverse((mana_spent, idx))) = queue.pop() {
+            if visited.len() % CANCELLATION_CHECK_INTERVAL == 0 {
+                cancellation.check()?;
+            }
+
+            if nodes[idx].won {
+                return Ok(Some(Self::victory(&nodes, idx, mana_spent)));
+            }
+
+            if !visited.insert(nodes[idx].battle) {
+                continue;
+            }
+
+            let battle = match nodes[idx].battle.begin_turn(self.difficulty) {
+                Outcome::Ongoing(battle) => battle,
+                Outcome::Lost => continue,
+                Outcome::Won => {
+                    nodes[idx].won = true;
+                    queue.push(Reverse((mana_spent, idx)));
+                    continue;
+                }
+            };
+
+            for spell in Spell::ALL {
+                if !battle.can_cast(spell) {
+                    continue;
+                }
+
+                let (next, won) = match battle.play(spell, self.boss) {
+                    Outcome::Ongoing(next) if !visited.contains(&next) => (next, false),
+                    Outcome::Won => (battle, true),
+                    Outcome::Ongoing(_) | Outcome::Lost => continue,
+                };
+
+                nodes.push(Node {
+                    battle: next,
+                    won,
+                    parent: Some((idx, spell)),
+                });
+
+                queue.push(Reverse((mana_spent + spell.cost(), nodes.len() - 1)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn victory(nodes: &[Node], mut idx: usize, mana_spent: u32) -> Victory {
+        let mut spells = Vec::new();
+
+        while let Some((parent, spell)) = nodes[idx].parent {
+            spells.push(spell);
+            idx = parent;
+        }
+
+        spells.reverse();
+
+        Victory { mana_spent, spells }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::puzzle::{answer, puzzle_solver};
+use crate::year_2015::day_21::helpers::Parser;
+use helpers::{Battle, Difficulty, SpellSearch, Victory};
+use show_option::ShowOption;
+
+pub mod helpers;
+
+const PLAYER_HIT_POINTS: u32 = 50;
+const PLAYER_MANA: u32 = 500;
+
+puzzle_solver!(
+    [2015, 22] = {
+        title: "Wizard Simulator 20XX",
+        parts: ["Least mana to win", "Least mana to win on hard"],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let boss = Parser::default().parse(input)?;
+            let battle = Battle::new(PLAYER_HIT_POINTS, PLAYER_MANA, &boss);
+
+            session.parsed();
+
+            let cancellation = session.cancellation();
+            let mut victories: Vec<(usize, Victory)> = Vec::new();
+
+            let mut least_mana = |part: usize, difficulty: Difficulty| {
+                let victory = SpellSearch::new(&boss, difficulty).cheapest_win(battle, &cancellation)?;
+                let mana_spent = victory.as_ref().map(|victory| victory.mana_spent);
+
+                victories.extend(victory.map(|victory| (part, victory)));
+
+                anyhow::Ok(mana_spent.show_or("none").to_string())
+            };
+
+            let least_mana_normal = session.solve_part(|| least_mana(1, Difficulty::Normal))?;
+            let least_mana_hard = session.solve_part(|| least_mana(2, Difficulty::Hard))?;
+
+            for (part, victory) in victories {
+                session.trace(format!("Part {part}: {victory}"));
+            }
+
+            answer!(least_mana_normal, least_mana_hard);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Session, Solver};
+    use crate::year_2015::day_21::helpers::Stats;
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(13, 226, "Poison -> Magic Missile")]
+    #[case(14, 641, "Recharge -> Shield -> Drain -> Poison -> Magic Missile")]
+    fn spell_search_tests(
+        #[case] boss_hit_points: u32,
+        #[case] expected_mana: u32,
+        #[case] expected_spells: &str,
+    ) {
+        let boss = Stats {
+            hit_points: boss_hit_points,
+            damage: 8,
+            armor: 0,
+        };
+
+        let cancellation = Session::start(None).cancellation();
+        let victory = SpellSearch::new(&boss, Difficulty::Normal)
+            .cheapest_win(Battle::new(10, 250, &boss), &cancellation)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(victory.mana_spent, expected_mana);
+        assert_eq!(victory.spells.iter().join(" -> "), expected_spells);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Damage: 8")]
+    #[case("Hit Points: 13\nDamage: eight")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
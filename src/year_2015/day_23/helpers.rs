@@ -0,0 +1,216 @@
+use crate::error::{Error, parse_capture};
+use crate::puzzle::Cancellation;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+
+const CANCELLATION_CHECK_INTERVAL: usize = 0x1000;
+
+pub struct Parser {
+    pattern: Regex,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            pattern: Regex::new(
+                r"^(?<instruction>hlf|tpl|inc|jmp|jie|jio) (?:(?<register>[ab])(?:, |$))?(?<offset>[+-]\d+)?$",
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl Parser {
+    pub fn parse(&self, line: &str) -> Result<Instruction, Error> {
+        let Some(caps) = self.pattern.captures(line) else {
+            return Err(Error::parse(1, format!("invalid instruction: {line}")));
+        };
+
+        let register = caps
+            .name("register")
+            .map(|register| match register.as_str() {
+                "a" => Register::A,
+                _ => Register::B,
+            });
+
+        let offset = match caps.name("offset") {
+            Some(_) => Some(parse_capture(&caps, "offset")?),
+            None => None,
+        };
+
+        let instruction = match (&caps["instruction"], register, offset) {
+            ("hlf", Some(register), None) => Instruction::Half(register),
+            ("tpl", Some(register), None) => Instruction::Triple(register),
+            ("inc", Some(register), None) => Instruction::Increment(register),
+            ("jmp", None, Some(offset)) => Instruction::Jump(offset),
+            ("jie", Some(register), Some(offset)) => Instruction::JumpIfEven(register, offset),
+            ("jio", Some(register), Some(offset)) => Instruction::JumpIfOne(register, offset),
+            _ => return Err(Error::parse(1, format!("invalid operands: {line}"))),
+        };
+
+        Ok(instruction)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(isize),
+    JumpIfEven(Register, isize),
+    JumpIfOne(Register, isize),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Half(register) => write!(f, "hlf {register}"),
+            Self::Triple(register) => write!(f, "tpl {register}"),
+            Self::Increment(register) => write!(f, "inc {register}"),
+            Self::Jump(offset) => write!(f, "jmp {offset:+}"),
+            Self::JumpIfEven(register, offset) => write!(f, "jie {register}, {offset:+}"),
+            Self::JumpIfOne(register, offset) => write!(f, "jio {register}, {offset:+}"),
+        }
+    }
+}
+
+/// An executed instruction along with the registers it left behind.
+pub struct Step {
+    count: usize,
+    pointer: usize,
+    instruction: Instruction,
+    registers: [u64; 2],
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{count:>6} {pointer:>3}: {instruction:<10} a={a} b={b}",
+            count = self.count,
+            pointer = self.pointer,
+            instruction = self.instruction.to_string(),
+            a = self.registers[0],
+            b = self.registers[1]
+        )
+    }
+}
+
+pub struct Machine<'a> {
+    program: &'a [Instruction],
+    registers: [u64; 2],
+    pointer: usize,
+    steps: usize,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(program: &'a [Instruction], a: u64, b: u64) -> Self {
+        Self {
+            program,
+            registers: [a, b],
+            pointer: 0,
+            steps: 0,
+        }
+    }
+
+    pub fn register(&self, register: Register) -> u64 {
+        self.registers[register as usize]
+    }
+
+    /// Runs the program until the instruction pointer leaves it, passing every
+    /// executed step to `trace`.
+    pub fn run(
+        &mut self,
+        step_limit: usize,
+        cancellation: &Cancellation,
+        mut trace: impl FnMut(&Step),
+    ) -> anyhow::Result<()> {
+        while let Some(instruction) = self.program.get(self.pointer).copied() {
+            if self.steps == step_limit {
+                return Err(Error::unsolvable(format!(
+                    "program did not halt within {step_limit} steps"
+                ))
+                .into());
+            }
+
+            if self.steps.is_multiple_of(CANCELLATION_CHECK_INTERVAL) {
+                cancellation.check()?;
+            }
+
+            let pointer = self.pointer;
+            self.execute(instruction)?;
+            self.steps += 1;
+
+            trace(&Step {
+                count: self.steps,
+                pointer,
+                instruction,
+                registers: self.registers,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn execute(&mut self, instruction: Instruction) -> Result<(), Error> {
+        let offset = match instruction {
+            Instruction::Half(register) => {
+                self.registers[register as usize] /= 2;
+                1
+            }
+            Instruction::Triple(register) => {
+                self.update(register, |value| value.checked_mul(3))?;
+                1
+            }
+            Instruction::Increment(register) => {
+                self.update(register, |value| value.checked_add(1))?;
+                1
+            }
+            Instruction::Jump(offset) => offset,
+            Instruction::JumpIfEven(register, offset)
+                if self.register(register).is_multiple_of(2) =>
+            {
+                offset
+            }
+            Instruction::JumpIfOne(register, offset) if self.register(register) == 1 => offset,
+            Instruction::JumpIfEven(..) | Instruction::JumpIfOne(..) => 1,
+        };
+
+        // Jumping before the first instruction halts the program like jumping past the last one.
+        self.pointer = self
+            .pointer
+            .checked_add_signed(offset)
+            .unwrap_or(usize::MAX);
+
+        Ok(())
+    }
+
+    fn update(
+        &mut self,
+        register: Register,
+        operation: impl Fn(u64) -> Option<u64>,
+    ) -> Result<(), Error> {
+        let value = &mut self.registers[register as usize];
+
+        *value = operation(*value)
+            .ok_or_else(|| Error::unsolvable(format!("register {register} overflowed")))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::ParseContext;
+use crate::puzzle::{answer, puzzle_solver};
+use helpers::{Machine, Parser, Register};
+use indoc::indoc;
+
+pub mod helpers;
+
+const STEP_LIMIT: usize = 1_000_000;
+
+const INPUT_JUMP_IF_ONE: &str = indoc! {
+    "inc b
+    jio b, +2
+    tpl b
+    inc b"
+};
+
+const INPUT_COLLATZ: &str = indoc! {
+    "jio a, +3
+    inc a
+    jmp +2
+    tpl a
+    jio a, +8
+    inc b
+    jie a, +4
+    tpl a
+    inc a
+    jmp +2
+    hlf a
+    jmp -7"
+};
+
+puzzle_solver!(
+    [2015, 23] = {
+        title: "Opening the Turing Lock",
+        parts: ["Register b", "Register b starting with a = 1"],
+        examples: [
+            (INPUT_JUMP_IF_ONE, ["2", "2"]),
+            (INPUT_COLLATZ, ["0", "7"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let parser = Parser::default();
+
+            let program = input
+                .lines()
+                .enumerate()
+                .map(|(idx, line)| parser.parse(line).at_line(idx + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            session.parsed();
+
+            let cancellation = session.cancellation();
+            let tracing = session.is_tracing();
+            let mut trace = Vec::new();
+
+            let mut register_b = |part: usize, a: u64| {
+                let mut machine = Machine::new(&program, a, 0);
+
+                machine.run(STEP_LIMIT, &cancellation, |step| {
+                    if tracing {
+                        trace.push(format!("Part {part}: {step}"));
+                    }
+                })?;
+
+                anyhow::Ok(machine.register(Register::B))
+            };
+
+            let register_b_from_zero = session.solve_part(|| register_b(1, 0))?;
+            let register_b_from_one = session.solve_part(|| register_b(2, 1))?;
+
+            for line in trace {
+                session.trace(line);
+            }
+
+            answer!(register_b_from_zero, register_b_from_one);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Session, Solver};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("inc a\njio a, +2\ntpl a\ninc a", 2, 0)]
+    #[case("inc b\ntpl b\nhlf b\njie b, -1", 0, 1)]
+    #[case("jmp -1\ninc a", 0, 0)]
+    fn machine_tests(#[case] input: &str, #[case] expected_a: u64, #[case] expected_b: u64) {
+        let parser = Parser::default();
+        let program = input
+            .lines()
+            .map(|line| parser.parse(line).unwrap())
+            .collect::<Vec<_>>();
+
+        let cancellation = Session::start(None).cancellation();
+        let mut machine = Machine::new(&program, 0, 0);
+        machine.run(STEP_LIMIT, &cancellation, |_| ()).unwrap();
+
+        assert_eq!(machine.register(Register::A), expected_a);
+        assert_eq!(machine.register(Register::B), expected_b);
+    }
+
+    #[test]
+    fn trace_tests() {
+        let parser = Parser::default();
+        let program = INPUT_JUMP_IF_ONE
+            .lines()
+            .map(|line| parser.parse(line).unwrap())
+            .collect::<Vec<_>>();
+
+        let cancellation = Session::start(None).cancellation();
+        let mut steps = Vec::new();
+
+        Machine::new(&program, 0, 0)
+            .run(STEP_LIMIT, &cancellation, |step| {
+                steps.push(step.to_string())
+            })
+            .unwrap();
+
+        assert_eq!(
+            steps,
+            [
+                "     1   0: inc b      a=0 b=1",
+                "     2   1: jio b, +2  a=0 b=1",
+                "     3   3: inc b      a=0 b=2",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("inc c")]
+    #[case("jmp a")]
+    #[case("jie a")]
+    #[case("inc a, +2")]
+    #[case("jmp +0")]
+    #[case("inc a\ntpl a\njmp -1")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::error::Error;
+use crate::puzzle::Cancellation;
+use itertools::Itertools;
+
+const CANCELLATION_CHECK_INTERVAL: usize = 0x1000;
+
+pub struct Packages {
+    weights: Vec<u64>,
+    prefix_sums: Vec<u64>,
+}
+
+impl Packages {
+    pub fn new(weights: Vec<u64>) -> Result<Self, Error> {
+        let weights = weights.into_iter().sorted().rev().collect_vec();
+        let mut prefix_sums = vec![0_u64];
+
+        for weight in &weights {
+            let sum = prefix_sums[prefix_sums.len() - 1];
+
+            prefix_sums.push(
+                sum.checked_add(*weight)
+                    .ok_or_else(|| Error::unsolvable("total package weight overflowed"))?,
+            );
+        }
+
+        Ok(Self {
+            weights,
+            prefix_sums,
+        })
+    }
+
+    fn total(&self) -> u64 {
+        self.prefix_sums[self.weights.len()]
+    }
+
+    /// Sums `count` consecutive weights starting at `start`.
+    fn sum(&self, start: usize, count: usize) -> u64 {
+        self.prefix_sums[start + count] - self.prefix_sums[start]
+    }
+
+    /// Finds the lowest quantum entanglement among the smallest first groups
+    /// that leave packages splittable into the remaining groups.
+    pub fn balance(
+        &self,
+        groups: usize,
+        cancellation: &Cancellation,
+    ) -> anyhow::Result<Option<u128>> {
+        if groups == 0 || !self.total().is_multiple_of(groups as u64) {
+            return Ok(None);
+        }
+
+        let target = self.total() / groups as u64;
+
+        for size in 1..=self.weights.len() {
+            let mut search = GroupSearch {
+                packages: self,
+                target,
+                groups,
+                size,
+                chosen: Vec::with_capacity(size),
+                best: None,
+                cancellation,
+                nodes: 0,
+            };
+
+            search.visit(0, 0, 1)?;
+
+            if search.best.is_some() {
+                return Ok(search.best);
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+struct GroupSearch<'a> {
+    packages: &'a Packages,
+    target: u64,
+    groups: usize,
+    size: usize,
+    chosen: Vec<usize>,
+    best: Option<u128>,
+    cancellation: &'a Cancellation,
+    nodes: usize,
+}
+
+impl GroupSearch<'_> {
+    fn visit(&mut self, start: usize, sum: u64, product: u128) -> anyhow::Result<()> {
+        if self.nodes.is_multiple_of(CANCELLATION_CHECK_INTERVAL) {
+            self.cancellation.check()?;
+        }
+
+        self.nodes += 1;
+
+        let weights = &self.packages.weights;
+        let remaining = self.size - self.chosen.len();
+        let needed = self.target - sum;
+
+        if remaining == 0 {
+            if needed == 0 && self.is_balanced() {
+                self.best = Some(product);
+            }
+
+            return Ok(());
+        }
+
+        // Weights are sorted in descending order, so the last ones are the lightest pick.
+        if self.packages.sum(weights.len() - remaining, remaining) > needed {
+            return Ok(());
+        }
+
+        let candidates = weights[..=weights.len() - remaining].iter().enumerate();
+
+        for (idx, &weight) in candidates.skip(start) {
+            if self.packages.sum(idx, remaining) < needed {
+                break;
+            }
+
+            if weight > needed {
+                continue;
+            }
+
+            let product = product
+                .checked_mul(u128::from(weight))
+                .ok_or_else(|| Error::unsolvable("quantum entanglement overflowed"))?;
+
+            if self.best.is_some_and(|best| product >= best) {
+                continue;
+            }
+
+            let sum = sum
+                .checked_add(weight)
+                .ok_or_else(|| Error::unsolvable("package group weight overflowed"))?;
+
+            self.chosen.push(idx);
+            self.visit(idx + 1, sum, product)?;
+            self.chosen.pop();
+        }
+
+        Ok(())
+    }
+
+    fn is_balanced(&self) -> bool {
+        let rest = self
+            .packages
+            .weights
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !self.chosen.contains(idx))
+            .map(|(_, weight)| *weight)
+            .collect_vec();
+
+        let mut groups = vec![0; self.groups - 1];
+
+        fill_groups(&rest, &mut groups, self.target)
+    }
+}
+
+/// Places every weight into a group without exceeding the target. Since the
+/// weights add up to the combined target, every group ends up full.
+fn fill_groups(weights: &[u64], groups: &mut [u64], target: u64) -> bool {
+    let Some((&weight, rest)) = weights.split_first() else {
+        return true;
+    };
+
+    for idx in 0..groups.len() {
+        // Groups with the same load are interchangeable, so only try the first of them.
+        if groups[idx] + weight > target || groups[..idx].contains(&groups[idx]) {
+            continue;
+        }
+
+        groups[idx] += weight;
+
+        if fill_groups(rest, groups, target) {
+            return true;
+        }
+
+        groups[idx] -= weight;
+    }
+
+    false
+}
@@ -0,0 +1,105 @@
+use crate::error::Error;
+use crate::puzzle::{answer, puzzle_solver};
+use helpers::Packages;
+use indoc::indoc;
+use show_option::ShowOption;
+
+pub mod helpers;
+
+const INPUT_EXAMPLE: &str = indoc! {
+    "1
+    2
+    3
+    4
+    5
+    7
+    8
+    9
+    10
+    11"
+};
+
+puzzle_solver!(
+    [2015, 24] = {
+        title: "It Hangs in the Balance",
+        parts: ["Quantum entanglement in three groups", "Quantum entanglement in four groups"],
+        examples: [
+            (INPUT_EXAMPLE, ["99", "44"]),
+        ],
+
+        fn run(&self, input: &str, session: &mut Session) -> anyhow::Result<Answer> {
+            let mut weights = Vec::new();
+
+            for (idx, line) in input.lines().enumerate() {
+                let weight = line
+                    .parse()
+                    .ok()
+                    .filter(|weight| *weight > 0)
+                    .ok_or_else(|| {
+                        Error::parse(1, format!("invalid package weight: {line}")).at_line(idx + 1)
+                    })?;
+
+                weights.push(weight);
+            }
+
+            if weights.is_empty() {
+                return Err(Error::parse(1, "no packages given").into());
+            }
+
+            let packages = Packages::new(weights)?;
+
+            session.parsed();
+
+            let cancellation = session.cancellation();
+
+            let quantum_entanglement = |groups| {
+                let quantum_entanglement = packages.balance(groups, &cancellation)?;
+
+                anyhow::Ok(quantum_entanglement.show_or("none").to_string())
+            };
+
+            let three_groups = session.solve_part(|| quantum_entanglement(3))?;
+            let four_groups = session.solve_part(|| quantum_entanglement(4))?;
+
+            answer!(three_groups, four_groups);
+        }
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Session, Solver};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[1, 2, 3], 2, Some(3))]
+    #[case(&[1, 2, 3, 4], 2, Some(4))]
+    #[case(&[1, 1, 4], 3, None)]
+    #[case(&[3, 3, 2, 2, 2], 3, None)]
+    #[case(&[5, 4, 3, 3, 3, 2, 2, 2], 4, None)]
+    #[case(&[6, 4, 3, 3, 2, 2, 2, 2], 4, Some(6))]
+    fn balance_tests(
+        #[case] weights: &[u64],
+        #[case] groups: usize,
+        #[case] expected: Option<u128>,
+    ) {
+        let cancellation = Session::start(None).cancellation();
+        let packages = Packages::new(weights.to_vec()).unwrap();
+
+        assert_eq!(packages.balance(groups, &cancellation).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("1\n\n2")]
+    #[case("0\n3")]
+    #[case("-4")]
+    #[case("18446744073709551615\n1")]
+    #[case("99999999999999999999")]
+    fn negative_tests(#[case] input: &str) {
+        let solution = Puzzle.solve(input);
+
+        assert!(solution.is_err());
+    }
+}
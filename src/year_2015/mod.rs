@@ -1,24 +1,29 @@
 use crate::puzzle::{Solver, puzzle_solver_selector};
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
 
 puzzle_solver_selector!(
     1 = day_01::Puzzle,
@@ -40,4 +45,9 @@ puzzle_solver_selector!(
     17 = day_17::Puzzle,
     18 = day_18::Puzzle,
     19 = day_19::Puzzle,
+    20 = day_20::Puzzle,
+    21 = day_21::Puzzle,
+    22 = day_22::Puzzle,
+    23 = day_23::Puzzle,
+    24 = day_24::Puzzle,
 );